@@ -1,42 +1,131 @@
 use crate::{
     adjective::{Adjective, AdjectiveInfo, AdjectiveKind},
     categories::{DeclInfo, Gender, IntoNumber},
-    declension::{AdjectiveDeclension, Declension},
+    declension::{AdjectiveDeclension, Declension, InflectError, unwrap_inflected},
     stress::AdjectiveStress,
     util::InflectionBuf,
     word::{Utf8Letter, Utf8LetterSlice, Word, WordBuf},
 };
 
 impl Adjective {
+    /// Inflects the adjective's full form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the adjective's stem doesn't fit its declension. See [`Adjective::try_inflect`].
     pub fn inflect(&self, info: DeclInfo) -> WordBuf {
-        self.info.inflect(self.stem.borrow(), info)
+        unwrap_inflected(self.try_inflect(info))
     }
+    /// Inflects the adjective's full form, returning an error instead of panicking if the
+    /// adjective's stem doesn't fit its declension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if the adjective's declension couldn't be applied to its stem.
+    pub fn try_inflect(&self, info: DeclInfo) -> Result<WordBuf, InflectError> {
+        let mut word = self.info.try_inflect(self.stem.borrow(), info)?;
+
+        if self.reflexive {
+            // Unlike verbs, adjectives have -ся even after vowels (учащаяся, учащиеся)
+            let mut buf = InflectionBuf::new(&mut word);
+            buf.append_to_ending("ся");
+            buf.finish(&mut word);
+        }
+        Ok(word)
+    }
+    /// Inflects the adjective's short form. Returns `None` if the adjective has no short forms
+    /// (e.g. pronoun adjectives and reflexive adjectives), or the specified short form is missing
+    /// according to the adjective's flags (—✕⌧). Forms marked as difficult by the flags are only
+    /// constructed if `force` is `true`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the adjective's stem doesn't fit its declension. See
+    /// [`Adjective::try_inflect_short`].
     pub fn inflect_short(&self, info: DeclInfo, force: bool) -> Option<WordBuf> {
-        self.info.inflect_short(self.stem.borrow(), info, force)
+        unwrap_inflected(self.try_inflect_short(info, force))
+    }
+    /// Inflects the adjective's short form, returning an error instead of panicking if the
+    /// adjective's stem doesn't fit its declension. See [`Adjective::inflect_short`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if the adjective's declension couldn't be applied to its stem.
+    pub fn try_inflect_short(
+        &self,
+        info: DeclInfo,
+        force: bool,
+    ) -> Result<Option<WordBuf>, InflectError> {
+        if self.reflexive {
+            return Ok(None);
+        }
+        self.info.try_inflect_short(self.stem.borrow(), info, force)
     }
+    /// Inflects the adjective's comparative form. Returns `None` if the adjective has no
+    /// comparative form (e.g. pronoun adjectives, reflexive adjectives and adjectives with `~`).
+    ///
+    /// Unlike the other forms, the comparative form can be constructed from any stem: it only
+    /// appends an ending, alternates the stem's last consonant and unstresses 'ё', none of which
+    /// can fail. Hence there's no fallible variant of this method.
     pub fn inflect_comparative(&self) -> Option<WordBuf> {
+        if self.reflexive {
+            return None;
+        }
         self.info.inflect_comparative(self.stem.borrow())
     }
 }
 
 impl AdjectiveInfo {
+    /// Inflects the full form of an adjective with the specified stem.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stem doesn't fit the declension. See [`AdjectiveInfo::try_inflect`].
     pub fn inflect(&self, stem: Word, info: DeclInfo) -> WordBuf {
+        unwrap_inflected(self.try_inflect(stem, info))
+    }
+    /// Inflects the full form of an adjective with the specified stem.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if the declension couldn't be applied to the stem.
+    pub fn try_inflect(&self, stem: Word, info: DeclInfo) -> Result<WordBuf, InflectError> {
         let mut word = WordBuf::with_stem(stem, 5);
         let mut buf = InflectionBuf::new(&mut word);
 
         if let Some(decl) = self.declension {
             match decl {
-                Declension::Adjective(decl) => decl.inflect(info, &mut buf),
-                Declension::Pronoun(decl) => decl.inflect(info, &mut buf),
-                Declension::Noun(_) => unimplemented!(), // Adjectives don't decline by noun declension
+                Declension::Adjective(decl) => decl.inflect(info, &mut buf)?,
+                Declension::Pronoun(decl) => decl.inflect(info, &mut buf)?,
+                // Adjectives don't decline by noun declension
+                Declension::Noun(_) => return Err(InflectError::IncompatibleDeclension),
             };
         }
 
         buf.finish(&mut word);
-        word
+        Ok(word)
     }
 
+    /// Inflects the short form of an adjective with the specified stem.
+    /// See [`Adjective::inflect_short`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stem doesn't fit the declension. See [`AdjectiveInfo::try_inflect_short`].
     pub fn inflect_short(&self, stem: Word, info: DeclInfo, force: bool) -> Option<WordBuf> {
+        unwrap_inflected(self.try_inflect_short(stem, info, force))
+    }
+    /// Inflects the short form of an adjective with the specified stem.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if the declension couldn't be applied to the stem.
+    pub fn try_inflect_short(
+        &self,
+        stem: Word,
+        info: DeclInfo,
+        force: bool,
+    ) -> Result<Option<WordBuf>, InflectError> {
         // Only regular adjective-declension adjectives can have short forms.
         // Also, check adjective flags (—✕⌧) to see if there are difficulties.
 
@@ -47,15 +136,17 @@ impl AdjectiveInfo {
             let mut word = WordBuf::with_stem(stem, 5);
             let mut buf = InflectionBuf::new(&mut word);
 
-            decl.inflect_short(info, &mut buf);
+            decl.inflect_short(info, &mut buf)?;
 
             buf.finish(&mut word);
-            Some(word)
+            Ok(Some(word))
         } else {
-            None
+            Ok(None)
         }
     }
 
+    /// Inflects the comparative form of an adjective with the specified stem.
+    /// See [`Adjective::inflect_comparative`].
     pub fn inflect_comparative(&self, stem: Word) -> Option<WordBuf> {
         // Only regular adjective-declension adjectives can have comparative forms.
         // Also, check adjective flag (~) to see if it has a comparative form.
@@ -78,7 +169,11 @@ impl AdjectiveInfo {
 }
 
 impl AdjectiveDeclension {
-    pub(crate) fn inflect(self, info: DeclInfo, buf: &mut InflectionBuf) {
+    pub(crate) fn inflect(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
         // Determine the stress position
         buf.stress = self.stress.full.pos();
 
@@ -86,11 +181,16 @@ impl AdjectiveDeclension {
         buf.append_to_ending(self.find_ending(info).as_str());
 
         if self.flags.has_alternating_yo() {
-            self.apply_ye_yo_alternation(buf);
+            self.apply_ye_yo_alternation(buf)?;
         }
+        Ok(())
     }
 
-    pub(crate) fn inflect_short(self, info: DeclInfo, buf: &mut InflectionBuf) {
+    pub(crate) fn inflect_short(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
         // Determine the stress position
         buf.stress = self.stress.short.pos(info.number, info.gender);
 
@@ -103,12 +203,13 @@ impl AdjectiveDeclension {
         {
             buf.shrink_stem_by(1);
         } else if self.flags.has_star() {
-            self.apply_vowel_alternation_short(info, buf);
+            self.apply_vowel_alternation_short(info, buf)?;
         }
 
         if self.flags.has_alternating_yo() {
-            self.apply_ye_yo_alternation(buf);
+            self.apply_ye_yo_alternation(buf)?;
         }
+        Ok(())
     }
 
     pub(crate) fn inflect_comparative(self, buf: &mut InflectionBuf) {
@@ -153,13 +254,15 @@ impl AdjectiveDeclension {
         }
     }
 
-    fn apply_ye_yo_alternation(self, buf: &mut InflectionBuf) {
+    fn apply_ye_yo_alternation(self, buf: &mut InflectionBuf) -> Result<(), InflectError> {
+        // Full forms are stressed according to the full form stress, and short forms - short one
+        let is_ending_stressed = buf.is_ending_stressed();
         let (stem, ending) = buf.stem_and_ending_mut();
 
         // If there's a 'ё' in the stem:
         if let Some(yo) = stem.iter_mut().find(|x| **x == Utf8Letter::Ё) {
             // If stress falls on the ending, unstress 'ё' in the stem into 'е'
-            if self.stress.full.is_ending_stressed() && ending.iter().any(|x| x.is_vowel()) {
+            if is_ending_stressed && ending.iter().any(|x| x.is_vowel()) {
                 *yo = Utf8Letter::Е;
             }
         } else {
@@ -167,7 +270,7 @@ impl AdjectiveDeclension {
 
             // Find the LAST unstressed 'е' in the stem
             let Some(ye) = stem.iter_mut().rfind(|x| **x == Utf8Letter::Е) else {
-                todo!("Handle absence of 'е' in the stem?")
+                return Err(InflectError::NoAlternatingYe);
             };
             // SAFETY: The InflectionBuf isn't modified between here and the assignment of ye.
             let ye = unsafe { &mut *&raw mut *ye };
@@ -190,20 +293,28 @@ impl AdjectiveDeclension {
                 *ye = Utf8Letter::Ё;
             }
         }
+        Ok(())
     }
 
-    fn apply_vowel_alternation_short(self, info: DeclInfo, buf: &mut InflectionBuf) {
+    fn apply_vowel_alternation_short(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
         use Utf8Letter::*;
 
         // Vowel alternation type B, only singular masculine form is affected
         if info.is_plural() || info.gender != Gender::Masculine {
-            return;
+            return Ok(());
         }
 
         // At this point, stem type is in range 1..=5 (consonant-ending stems).
         // Stem type 6 adjectives don't have *, and stem type 7 adjectives don't exist.
         // So, it's safe to assume that the last stem char is a consonant.
         let stem = buf.stem_mut();
+        if stem.len() < 2 {
+            return Err(InflectError::InvalidVowelAlternation);
+        }
         let last = stem.last().copied();
         let pre_last = stem.get_mut(stem.len() - 2);
 
@@ -216,7 +327,7 @@ impl AdjectiveDeclension {
             let stressed = last != Some(Ц)
                 && self.stress.short.is_ending_stressed(info.number, info.gender).unwrap_or(true);
             *pre_last = if stressed { Ё } else { Е };
-            return;
+            return Ok(());
         }
 
         // 3) in all other cases, insert a letter between two last chars
@@ -246,5 +357,6 @@ impl AdjectiveDeclension {
             }
         };
         buf.insert_between_last_two_stem_chars(insert_between.as_str());
+        Ok(())
     }
 }
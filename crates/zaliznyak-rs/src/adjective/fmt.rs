@@ -1,7 +1,8 @@
 use crate::{
     adjective::{AdjectiveFlags, AdjectiveInfo, AdjectiveKind},
     declension::{
-        AdjectiveDeclension, DECLENSION_MAX_LEN, Declension, DeclensionKind, PronounDeclension,
+        AdjectiveDeclension, DECLENSION_MAX_LEN, Declension, DeclensionKind, NounDeclension,
+        PronounDeclension,
     },
     util::UnsafeBuf,
 };
@@ -37,7 +38,9 @@ impl AdjectiveInfo {
                 match decl {
                     Declension::Adjective(_) => dst.push_str("п "),
                     Declension::Pronoun(_) => dst.push_str("мс "),
-                    Declension::Noun(_) => unimplemented!(), // Adjectives don't decline by noun declension
+                    // Adjectives don't decline by noun declension, and such info can't be parsed
+                    //   back. Still, format it in brackets, so that Display never panics.
+                    Declension::Noun(_) => {},
                 }
             }
 
@@ -48,8 +51,8 @@ impl AdjectiveInfo {
                 Declension::Pronoun(decl) => {
                     dst.push_fmt2(decl, PronounDeclension::fmt_to);
                 },
-                Declension::Noun(_) => {
-                    unimplemented!() // Adjectives don't decline by noun declension
+                Declension::Noun(decl) => {
+                    dst.push_fmt2(decl, NounDeclension::fmt_to);
                 },
             }
 
@@ -60,6 +63,8 @@ impl AdjectiveInfo {
             if self.kind == AdjectiveKind::Regular {
                 dst.push_fmt2(self.flags, AdjectiveFlags::fmt_to);
             }
+        } else {
+            dst.push('0');
         }
 
         dst.finish()
@@ -110,10 +115,10 @@ mod tests {
     use crate::{
         adjective::AdjectiveFlags,
         declension::{
-            AdjectiveDeclension, AdjectiveStemType, DeclensionFlags, PronounDeclension,
-            PronounStemType,
+            AdjectiveDeclension, AdjectiveStemType, DeclensionFlags, NounStemType,
+            PronounDeclension, PronounStemType,
         },
-        stress::{AdjectiveStress, PronounStress},
+        stress::{AdjectiveStress, NounStress, PronounStress},
     };
 
     #[test]
@@ -174,6 +179,21 @@ mod tests {
             "п <мс 6*f>",
         );
 
+        // Adjectives don't decline by noun declension, but it's still formatted in brackets
+        assert_eq!(
+            AdjectiveInfo {
+                kind: AdjectiveKind::Regular,
+                flags: AdjectiveFlags::empty(),
+                declension: Some(Declension::Noun(NounDeclension {
+                    stem_type: NounStemType::Type1,
+                    stress: NounStress::A,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }
+            .to_string(),
+            "п <1a>",
+        );
+
         // Adjectives with flags
         assert_eq!(
             AdjectiveInfo {
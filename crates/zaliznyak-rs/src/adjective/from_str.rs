@@ -0,0 +1,239 @@
+use crate::{
+    adjective::{AdjectiveFlags, AdjectiveInfo, AdjectiveKind},
+    declension::{
+        AdjectiveDeclension, Declension, DeclensionKind, ParseDeclensionError, PronounDeclension,
+    },
+    util::{PartialFromStr, UnsafeParser},
+    word::Utf8Letter,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum ParseAdjectiveInfoError {
+    #[error("invalid adjective type")]
+    InvalidKind,
+    #[error("error parsing declension: {0}")]
+    InvalidDeclension(ParseDeclensionError),
+    #[error("declension type not compatible with adjective type")]
+    IncompatibleDeclension,
+    #[error("invalid format")]
+    Invalid,
+}
+
+impl const PartialFromStr for AdjectiveInfo {
+    fn partial_from_str(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        // 'п', 'мс-п' or 'числ.-п', followed by a space
+        let (kind, mut decl_kind) = if parser.skip_str("п ") {
+            (AdjectiveKind::Regular, DeclensionKind::Adjective)
+        } else if parser.skip_str("мс-п ") {
+            (AdjectiveKind::Pronoun, DeclensionKind::Pronoun)
+        } else if parser.skip_str("числ.-п ") {
+            (AdjectiveKind::Numeral, DeclensionKind::Noun)
+        } else {
+            return Err(Self::Err::InvalidKind);
+        };
+
+        if parser.skip('0') {
+            // Don't expect anything else after 0
+            return Ok(Self { kind, flags: AdjectiveFlags::empty(), declension: None });
+        }
+
+        // Expect unusual declension in brackets: '<п ...>' or '<мс ...>'
+        let in_brackets = parser.skip('<');
+        if in_brackets {
+            decl_kind = match parser.peek_letter() {
+                Some(Utf8Letter::П) => DeclensionKind::Adjective,
+                Some(Utf8Letter::М) => {
+                    parser.forward(2);
+                    if !matches!(parser.peek_letter(), Some(Utf8Letter::С)) {
+                        return Err(Self::Err::IncompatibleDeclension);
+                    }
+                    DeclensionKind::Pronoun
+                },
+                _ => return Err(Self::Err::IncompatibleDeclension),
+            };
+            parser.forward(2);
+
+            // Expect another space between declension type and declension
+            if !parser.skip(' ') {
+                return Err(Self::Err::Invalid);
+            }
+        }
+
+        // Parse declension of detected type
+        let declension = match decl_kind {
+            DeclensionKind::Adjective => Declension::Adjective(
+                AdjectiveDeclension::partial_from_str(parser)
+                    .map_err(Self::Err::InvalidDeclension)?,
+            ),
+            DeclensionKind::Pronoun => Declension::Pronoun(
+                PronounDeclension::partial_from_str(parser)
+                    .map_err(Self::Err::InvalidDeclension)?,
+            ),
+            // Adjectives don't decline by noun declension
+            DeclensionKind::Noun => return Err(Self::Err::IncompatibleDeclension),
+        };
+
+        // Close brackets
+        if in_brackets && !parser.skip('>') {
+            return Err(Self::Err::Invalid);
+        }
+
+        // Only regular adjectives can have short form and comparative flags
+        let flags = match kind {
+            AdjectiveKind::Regular => AdjectiveFlags::partial_from_str(parser)?,
+            _ => AdjectiveFlags::empty(),
+        };
+
+        Ok(Self { kind, flags, declension: Some(declension) })
+    }
+}
+
+impl const PartialFromStr for AdjectiveFlags {
+    fn partial_from_str(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        let mut flags = Self::empty();
+
+        if parser.skip('—') {
+            flags = flags.union(Self::MINUS);
+        } else if parser.skip('✕') {
+            flags = flags.union(Self::CROSS);
+        } else if parser.skip('⌧') {
+            flags = flags.union(Self::BOXED_CROSS);
+        }
+        if parser.skip('~') {
+            flags = flags.union(Self::NO_COMPARATIVE_FORM);
+        }
+        Ok(flags)
+    }
+}
+
+impl const std::str::FromStr for AdjectiveInfo {
+    type Err = ParseAdjectiveInfoError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_or_err(s, Self::Err::Invalid)
+    }
+}
+impl const std::str::FromStr for AdjectiveFlags {
+    type Err = ParseAdjectiveInfoError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_or_err(s, Self::Err::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        declension::{AdjectiveStemType, DeclensionFlags, PronounStemType},
+        stress::{AdjectiveStress, PronounStress},
+    };
+
+    #[test]
+    fn parse() {
+        // Some simple adjectives
+        assert_eq!(
+            "п 4*a/c′".parse(),
+            Ok(AdjectiveInfo {
+                kind: AdjectiveKind::Regular,
+                flags: AdjectiveFlags::empty(),
+                declension: Some(Declension::Adjective(AdjectiveDeclension {
+                    stem_type: AdjectiveStemType::Type4,
+                    stress: AdjectiveStress::A_Cp,
+                    flags: DeclensionFlags::STAR,
+                })),
+            }),
+        );
+        assert_eq!(
+            "мс-п 2*b".parse(),
+            Ok(AdjectiveInfo {
+                kind: AdjectiveKind::Pronoun,
+                flags: AdjectiveFlags::empty(),
+                declension: Some(Declension::Pronoun(PronounDeclension {
+                    stem_type: PronounStemType::Type2,
+                    stress: PronounStress::B,
+                    flags: DeclensionFlags::STAR,
+                })),
+            }),
+        );
+
+        // Adjectives with different declension
+        assert_eq!(
+            "числ.-п <п 1a>".parse(),
+            Ok(AdjectiveInfo {
+                kind: AdjectiveKind::Numeral,
+                flags: AdjectiveFlags::empty(),
+                declension: Some(Declension::Adjective(AdjectiveDeclension {
+                    stem_type: AdjectiveStemType::Type1,
+                    stress: AdjectiveStress::A,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }),
+        );
+        assert_eq!(
+            "п <мс 6*f>".parse(),
+            Ok(AdjectiveInfo {
+                kind: AdjectiveKind::Regular,
+                flags: AdjectiveFlags::empty(),
+                declension: Some(Declension::Pronoun(PronounDeclension {
+                    stem_type: PronounStemType::Type6,
+                    stress: PronounStress::F,
+                    flags: DeclensionFlags::STAR,
+                })),
+            }),
+        );
+
+        // Adjectives with flags
+        assert_eq!(
+            "п 3a/b✕~".parse(),
+            Ok(AdjectiveInfo {
+                kind: AdjectiveKind::Regular,
+                flags: AdjectiveFlags::CROSS | AdjectiveFlags::NO_COMPARATIVE_FORM,
+                declension: Some(Declension::Adjective(AdjectiveDeclension {
+                    stem_type: AdjectiveStemType::Type3,
+                    stress: AdjectiveStress::A_B,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }),
+        );
+        assert_eq!("—".parse(), Ok(AdjectiveFlags::MINUS));
+        assert_eq!(
+            "⌧~".parse(),
+            Ok(AdjectiveFlags::BOXED_CROSS | AdjectiveFlags::NO_COMPARATIVE_FORM)
+        );
+        assert_eq!("~—".parse::<AdjectiveFlags>(), Err(ParseAdjectiveInfoError::Invalid));
+
+        // Indeclinable adjectives
+        assert_eq!(
+            "п 0".parse(),
+            Ok(AdjectiveInfo {
+                kind: AdjectiveKind::Regular,
+                flags: AdjectiveFlags::empty(),
+                declension: None,
+            }),
+        );
+
+        // Round-trip formatting
+        for s in ["п 1*a/c′", "п 1b/c⌧", "п 2a—~", "мс-п 1a", "числ.-п <п 1a>", "п <мс 6*f>", "п 0"]
+        {
+            assert_eq!(s.parse::<AdjectiveInfo>().unwrap().to_string(), s);
+        }
+
+        // Invalid formats
+        assert_eq!("м 1a".parse::<AdjectiveInfo>(), Err(ParseAdjectiveInfoError::InvalidKind));
+        assert_eq!(
+            "числ.-п 1a".parse::<AdjectiveInfo>(),
+            Err(ParseAdjectiveInfoError::IncompatibleDeclension)
+        );
+        assert_eq!(
+            "п <м 1a>".parse::<AdjectiveInfo>(),
+            Err(ParseAdjectiveInfoError::IncompatibleDeclension)
+        );
+        assert!(matches!(
+            "п 9a".parse::<AdjectiveInfo>(),
+            Err(ParseAdjectiveInfoError::InvalidDeclension(_))
+        ));
+        assert_eq!("п <п 1a".parse::<AdjectiveInfo>(), Err(ParseAdjectiveInfoError::Invalid));
+        assert_eq!("мс-п 1a~".parse::<AdjectiveInfo>(), Err(ParseAdjectiveInfoError::Invalid));
+    }
+}
@@ -1,17 +1,23 @@
-use crate::{declension::Declension, word::WordBuf};
+use crate::{
+    declension::Declension,
+    word::{Word, WordBuf},
+};
 use thiserror::Error;
 
 mod declension;
 mod flags;
 mod fmt;
+mod from_str;
 
 pub use flags::*;
+pub use from_str::*;
 
 #[derive(Debug, Clone, Eq, Hash)]
 #[derive_const(PartialEq)]
 pub struct Adjective {
     stem: WordBuf,
     info: AdjectiveInfo,
+    reflexive: bool,
 }
 
 #[derive(Debug, Copy, Eq, Hash)]
@@ -41,6 +47,49 @@ pub enum NewAdjectiveError {
 
 impl Adjective {
     pub const fn from_stem(stem: WordBuf, info: AdjectiveInfo) -> Self {
-        Self { stem, info }
+        Self { stem, info, reflexive: false }
+    }
+    /// Constructs a reflexive adjective, that has the -ся postfix in all of its forms (учащийся,
+    /// учащаяся). Reflexive adjectives don't have short and comparative forms.
+    pub const fn from_stem_reflexive(stem: WordBuf, info: AdjectiveInfo) -> Self {
+        Self { stem, info, reflexive: true }
+    }
+    /// Constructs an adjective from its nominative masculine singular form, identifying its stem
+    /// according to the adjective's declension. Adjectives ending in -ся are reflexive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NewAdjectiveError::InvalidStem`] if the word is not a valid stressed word or its
+    /// stem couldn't be identified, and [`NewAdjectiveError::NotMatchingStemType`] if the
+    /// identified stem type doesn't match the declension's stem type.
+    pub fn from_word(word: &str, info: AdjectiveInfo) -> Result<Self, NewAdjectiveError> {
+        let mut stem: WordBuf = word.parse().or(Err(NewAdjectiveError::InvalidStem))?;
+        let mut reflexive = false;
+
+        if let Some(decl) = info.declension {
+            let (matches, is_reflexive) =
+                decl.identify_trim(&mut stem).ok_or(NewAdjectiveError::InvalidStem)?;
+            if !matches {
+                return Err(NewAdjectiveError::NotMatchingStemType);
+            }
+            reflexive = is_reflexive;
+        }
+        Ok(Self { stem, info, reflexive })
+    }
+
+    /// Returns `true` if the adjective has the reflexive -ся postfix.
+    #[must_use]
+    pub const fn is_reflexive(&self) -> bool {
+        self.reflexive
+    }
+    /// Returns the adjective's stem.
+    #[must_use]
+    pub const fn stem(&self) -> Word<'_> {
+        self.stem.borrow()
+    }
+    /// Returns the adjective's grammatical info.
+    #[must_use]
+    pub const fn info(&self) -> AdjectiveInfo {
+        self.info
     }
 }
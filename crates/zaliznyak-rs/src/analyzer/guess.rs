@@ -0,0 +1,420 @@
+use crate::{
+    analyzer::{Analysis, forms, normalize},
+    categories::{Animacy, Case, CaseEx, DeclInfo, Gender, Number},
+    declension::{
+        AdjectiveDeclension, AdjectiveStemType, Declension, DeclensionFlags, NounDeclension,
+        NounStemType,
+    },
+    dictionary::{Entry, Lexicon},
+    noun::{Noun, NounFlags, NounInfo},
+    stress::{AdjectiveStress, NounStress},
+    word::{Utf8Letter, Utf8LetterSlice},
+};
+use std::{collections::HashSet, sync::LazyLock};
+
+/// A candidate analysis of a word form, guessed without a dictionary. See [`guess`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Candidate {
+    /// The guessed lemma (dictionary form) of the word.
+    pub lemma: String,
+    /// The guessed noun info of the lemma.
+    pub info: NounInfo,
+    /// The case of the word form.
+    pub case: CaseEx,
+    /// The number of the word form.
+    pub number: Number,
+}
+
+/// Guesses the possible analyses of a word form as a noun, without a dictionary.
+///
+/// Every ending from the declension tables, that the word form ends with, is stripped, and the
+/// remaining stem is used to reconstruct possible lemmas, reversing the vowel alternations:
+/// fleeting vowels (*: **бойц-ами** --- **боец**, **окон** --- **окн-о**) and the unique
+/// alternations (°: **котят-а** --- **котёнок**, **горожан-е** --- **горожанин**). The stem
+/// types of the lemmas are identified from their endings (see [`NounStemType::identify`]), and
+/// every lemma is then inflected, to only keep the ones that actually produce the word form.
+///
+/// Since the stress of the word form isn't known, only stress schemas **a** and **b** are tried,
+/// and the first one that produces the word form is returned. Only nouns of the noun declension
+/// are guessed, so substantivized adjectives (**столовая**), pluralia tantum and indeclinable
+/// nouns aren't. The results are ambiguous by design: any noun, that could have produced the word
+/// form, is returned. To narrow them down, check them against a dictionary with
+/// [`Lexicon::analyze`].
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     analyzer,
+///     categories::{CaseEx, Number},
+/// };
+///
+/// let candidates = analyzer::guess("бойцами");
+///
+/// let boets = candidates.iter().find(|x| x.lemma == "боец").unwrap();
+/// assert_eq!(boets.info.to_string(), "м 5*a");
+/// assert_eq!((boets.case, boets.number), (CaseEx::Instrumental, Number::Plural));
+/// ```
+#[must_use]
+pub fn guess(form: &str) -> Vec<Candidate> {
+    let Some(form) = to_letters(&normalize(form)) else { return Vec::new() };
+
+    let mut seen = HashSet::new();
+    let mut candidates = Vec::new();
+
+    for stress in [NounStress::A, NounStress::B] {
+        for (lemma_stem, flags) in lemma_stems(&form) {
+            for gender in Gender::VALUES {
+                for stem_type in (1..=8).filter_map(NounStemType::from_digit) {
+                    // Stem type 8 is used by feminine nouns, with only a few exceptions (путь, имя)
+                    if stem_type == NounStemType::Type8 && gender != Gender::Feminine {
+                        continue;
+                    }
+                    let decl = NounDeclension { stem_type, stress, flags };
+
+                    for animacy in Animacy::VALUES {
+                        let Some((lemma, noun)) = construct(&lemma_stem, decl, gender, animacy)
+                        else {
+                            continue;
+                        };
+                        let Ok(paradigm) = noun.try_paradigm() else { continue };
+
+                        for (case, number, word) in forms(&paradigm) {
+                            // Animacy only affects the accusative case
+                            if animacy == Animacy::Animate && case != CaseEx::Accusative
+                                || *normalize(word.as_str()) != *form.as_str()
+                            {
+                                continue;
+                            }
+                            if seen.insert((lemma.clone(), case, number)) {
+                                let (lemma, info) = (lemma.clone(), noun.info());
+                                candidates.push(Candidate { lemma, info, case, number });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    candidates
+}
+
+impl Lexicon {
+    /// Analyzes a word form as a noun, using the nouns in this lexicon.
+    ///
+    /// Like [`guess`], possible lemmas are reconstructed by stripping endings and reversing vowel
+    /// alternations, but instead of guessing their declension, the lemmas are looked up in the
+    /// lexicon, and only the nouns, that have the word form in their paradigms, are returned.
+    /// Unlike [`guess`], this also analyzes substantivized adjectives, pluralia tantum and
+    /// indeclinable nouns. Letter case and stress marks are ignored, and 'е' and 'ё' aren't
+    /// distinguished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{
+    ///     categories::{CaseEx, Number},
+    ///     dictionary::Lexicon,
+    /// };
+    ///
+    /// let (lexicon, _) = Lexicon::parse("бое́ц мо 5*b\nёж мо 4b\nстоло́вая ж <п 1a>\n");
+    ///
+    /// let analyses = lexicon.analyze("бойцами");
+    /// assert_eq!(analyses.len(), 1);
+    /// assert_eq!(analyses[0].lemma, "боец");
+    /// assert_eq!((analyses[0].case, analyses[0].number), (CaseEx::Instrumental, Number::Plural));
+    ///
+    /// assert_eq!(lexicon.analyze("Ежа́").len(), 2); // Р.ед. and В.ед.
+    /// assert_eq!(lexicon.analyze("столовой").len(), 4);
+    /// ```
+    #[must_use]
+    pub fn analyze(&self, form: &str) -> Vec<Analysis<'_>> {
+        let Some(letters) = to_letters(&normalize(form)) else { return Vec::new() };
+
+        let mut lemmas = HashSet::new();
+        for (stem, _) in lemma_stems(&letters) {
+            for ending in LEMMA_ENDINGS {
+                let lemma = stem.as_str().to_owned() + ending;
+                lemmas.extend(yo_variants(&lemma));
+                lemmas.insert(lemma);
+            }
+        }
+
+        let mut analyses = Vec::new();
+        for (index, lemma, entry) in lemmas.iter().flat_map(|x| self.get_indexed(x)) {
+            let Entry::Noun(noun) = entry else { continue };
+            let Ok(paradigm) = noun.try_paradigm() else { continue };
+
+            for (case, number, word) in forms(&paradigm) {
+                if *normalize(word.as_str()) == *letters.as_str() {
+                    analyses.push((index, Analysis { lemma, info: noun.info(), case, number }));
+                }
+            }
+        }
+        // Order the analyses by the entries' order in the lexicon
+        analyses.sort_by_key(|x| x.0);
+        analyses.into_iter().map(|x| x.1).collect()
+    }
+}
+
+/// Nominative forms' endings of the lemmas to look up in a lexicon: nouns, substantivized
+/// adjectives, pluralia tantum and indeclinable nouns (with an empty ending). Lemmas with 'ё' are
+/// looked up separately, see [`yo_variants`].
+const LEMMA_ENDINGS: [&str; 16] =
+    ["", "ь", "й", "а", "я", "о", "е", "ы", "и", "ый", "ий", "ой", "ая", "яя", "ое", "ее"];
+
+/// All endings of nouns and adjectives in the declension tables, with 'ё' replaced with 'е'.
+static ENDINGS: LazyLock<Vec<Vec<Utf8Letter>>> = LazyLock::new(|| {
+    let mut endings = Vec::new();
+    let mut push = |ending: &[Utf8Letter]| {
+        let ending = normalize_letters(ending);
+        if !endings.contains(&ending) {
+            endings.push(ending);
+        }
+    };
+
+    for (gender, number, case) in all_decl_infos() {
+        let info = DeclInfo { case, number, gender, animacy: Animacy::Inanimate };
+
+        for stem_type in (1..=8).filter_map(NounStemType::from_digit) {
+            for stress in [NounStress::A, NounStress::B] {
+                let flags = DeclensionFlags::empty();
+                push(NounDeclension { stem_type, stress, flags }.find_ending(info));
+            }
+        }
+        // Substantivized adjectives: столовая, рабочий
+        for stem_type in (1..=6).filter_map(AdjectiveStemType::from_digit) {
+            for stress in [AdjectiveStress::A, AdjectiveStress::B] {
+                let flags = DeclensionFlags::empty();
+                push(AdjectiveDeclension { stem_type, stress, flags }.find_ending(info));
+            }
+        }
+    }
+    endings
+});
+
+/// Returns all combinations of gender, number and case.
+fn all_decl_infos() -> impl Iterator<Item = (Gender, Number, Case)> {
+    Gender::VALUES.into_iter().flat_map(|gender| {
+        Number::VALUES.into_iter().flat_map(move |number| {
+            Case::VALUES.into_iter().map(move |case| (gender, number, case))
+        })
+    })
+}
+
+/// Returns the possible stems of the lemmas, that the word form could be inflected from, along
+/// with the flags of vowel alternations, that produce the word form's stem from them.
+fn lemma_stems(form: &[Utf8Letter]) -> Vec<(Vec<Utf8Letter>, DeclensionFlags)> {
+    let mut stems = Vec::new();
+
+    // Strip all endings from the declension tables, that the word form ends with
+    for ending in ENDINGS.iter() {
+        let Some(stem) = form.strip_suffix(ending.as_slice()) else { continue };
+        if stem.is_empty() {
+            continue;
+        }
+        for variant in unalternate(stem) {
+            if !stems.contains(&variant) {
+                stems.push(variant);
+            }
+        }
+    }
+    stems
+}
+
+/// Returns the stem itself, and the stems that it could have been alternated from, along with the
+/// flags of the alternations.
+fn unalternate(stem: &[Utf8Letter]) -> Vec<(Vec<Utf8Letter>, DeclensionFlags)> {
+    use Utf8Letter::*;
+
+    let mut variants = vec![(stem.to_vec(), DeclensionFlags::empty())];
+    let replace = |len: usize, with: &[Utf8Letter]| [&stem[..stem.len() - len], with].concat();
+    let mut star = |x: Vec<Utf8Letter>| variants.push((x, DeclensionFlags::STAR));
+
+    match *stem {
+        // The fleeting vowel is removed in all forms but nominative: бойц- --- боец, льв- --- лев
+        [.., Й | Ь, last] if last.is_consonant() => star(replace(2, &[Е, last])),
+        // кусков --- кусок, замочка --- замочек
+        [.., prev, last] if prev.is_consonant() && last.is_consonant() => {
+            star(replace(1, &[О, last]));
+            star(replace(1, &[Е, last]));
+        },
+        _ => {},
+    }
+    match *stem {
+        // The fleeting vowel is inserted in genitive plural: окон --- окно, гаек --- гайка,
+        // салец --- сальце
+        [.., prev, О | Е, last] if last.is_consonant() => {
+            if prev.is_consonant() {
+                star(replace(2, &[last]));
+                star(replace(2, &[Ь, last]));
+            } else {
+                star(replace(2, &[Й, last]));
+            }
+        },
+        // статей --- статья, лгуний --- лгунья
+        [.., prev, Е | И] if prev.is_consonant() => star(replace(1, &[Ь])),
+        _ => {},
+    }
+
+    let circle = match *stem {
+        // котята --- котёнок, медвежата --- медвежонок
+        [.., Я, Т] => Some(replace(2, &[Ё, Н, О, К])),
+        [.., Ж | Ш | Ч | Щ, А, Т] => Some(replace(2, &[О, Н, О, К])),
+        // горожане --- горожанин, крестьяне --- крестьянин
+        [.., А | Я, Н] => Some(replace(0, &[И, Н])),
+        // времена --- время
+        [.., М, Е, Н] => Some(replace(2, &[])),
+        _ => None,
+    };
+    variants.extend(circle.map(|x| (x, DeclensionFlags::CIRCLE)));
+    variants
+}
+
+/// Constructs a noun from the stem of its lemma and its declension, returning the lemma and the
+/// noun. Returns `None` if the stem has no vowels, or the lemma doesn't fit the declension.
+fn construct(
+    stem: &[Utf8Letter],
+    decl: NounDeclension,
+    gender: Gender,
+    animacy: Animacy,
+) -> Option<(String, Noun)> {
+    let info = DeclInfo { case: Case::Nominative, number: Number::Singular, gender, animacy };
+    let lemma = [stem, decl.find_ending(info)].concat();
+    let lemma = lemma.as_str();
+
+    // The stress in the stem doesn't affect the endings, only the stress schema does
+    let stress_at = match stem.iter().position(|&x| x == Utf8Letter::Ё) {
+        Some(yo) => yo,
+        None => stem.iter().rposition(|x| x.is_vowel())?,
+    };
+    let mut word = lemma.to_owned();
+    word.insert(stress_at * 2 + 2, '\u{301}');
+
+    let info = NounInfo {
+        declension: Some(Declension::Noun(decl)),
+        declension_gender: gender,
+        gender: gender.into(),
+        animacy,
+        tantum: None,
+        flags: NounFlags::empty(),
+    };
+    let noun = Noun::from_word(&word, info).ok()?;
+
+    // Skip lemmas, whose nominative form isn't the lemma itself (ежа as a masculine noun)
+    let nominative = noun.try_inflect(CaseEx::Nominative, Number::Singular).ok()?;
+    (nominative.as_str() == lemma).then(|| (lemma.to_owned(), noun))
+}
+
+/// Converts a normalized word form to letters. Returns `None` if it isn't spelled in Cyrillic.
+fn to_letters(form: &str) -> Option<Vec<Utf8Letter>> {
+    form.chars().map(Utf8Letter::from_char).collect()
+}
+
+/// Replaces 'ё' with 'е' in the letters.
+fn normalize_letters(letters: &[Utf8Letter]) -> Vec<Utf8Letter> {
+    let yo_to_ye = |&x| if x == Utf8Letter::Ё { Utf8Letter::Е } else { x };
+    letters.iter().map(yo_to_ye).collect()
+}
+
+/// Returns the variants of a normalized lemma, with one of its 'е' replaced with 'ё'.
+fn yo_variants(lemma: &str) -> impl Iterator<Item = String> {
+    lemma.match_indices('е').map(|(i, _)| format!("{}ё{}", &lemma[..i], &lemma[i + 2..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fmt(lemma: &str, info: NounInfo, case: CaseEx, number: Number) -> String {
+        format!("{lemma} {info} {} {}", case.abbr_upper(), number.abbr_upper())
+    }
+
+    #[test]
+    fn guess() {
+        let guess = |form| -> Vec<String> {
+            super::guess(form)
+                .into_iter()
+                .map(|x| fmt(&x.lemma, x.info, x.case, x.number))
+                .collect()
+        };
+        let assert_guess = |form, expected: &str| {
+            let guesses = guess(form);
+            assert!(guesses.iter().any(|x| x == expected), "{form}: {guesses:?}");
+        };
+
+        // Stems without alternations
+        assert_guess("столами", "стол м 1a INS PL");
+        assert_guess("руки", "рука ж 3a GEN SG");
+        assert_guess("руки", "рука ж 3a NOM PL");
+        assert_guess("Окна́", "окно с 1a NOM PL");
+        // Stressed endings
+        assert_guess("бойцов", "боец м 5*b GEN PL");
+        assert_guess("бойцов", "боец мо 5*b ACC PL");
+        // Fleeting vowels (*)
+        assert_guess("бойцами", "боец м 5*a INS PL");
+        assert_guess("дня", "день м 2*a GEN SG");
+        assert_guess("окон", "окно с 1*a GEN PL");
+        assert_guess("ложек", "ложка ж 3*a GEN PL");
+        assert_guess("гаек", "гайка ж 3*a GEN PL");
+        assert_guess("статей", "статья ж 6*b GEN PL");
+        // Unique alternations (°)
+        assert_guess("котята", "котёнок м 3°a NOM PL");
+        assert_guess("горожанами", "горожанин м 1°a INS PL");
+
+        // Lemmas are only returned if they produce the form
+        assert!(!guess("ежа").iter().any(|x| x.starts_with("ежа м")));
+        assert!(!guess("столами").iter().any(|x| x.starts_with("столам ")));
+
+        assert_eq!(guess("stol"), [] as [String; 0]);
+        assert_eq!(guess(""), [] as [String; 0]);
+    }
+
+    #[test]
+    fn analyze_lexicon() {
+        let text = "\
+бое́ц мо 5*b
+стол м 1b
+рука́ ж 3f′
+окно́ с 1*d
+ёж мо 4b
+но́жницы мн. <ж 5a>
+столо́вая ж <п 1a>
+ко́фе м 0
+котёнок мо 3°a
+вре́мя с 8°c
+лес м 1c, П2 (в)
+";
+        let (lexicon, errors) = Lexicon::parse(text);
+        assert_eq!(errors, []);
+
+        let analyze = |form| -> Vec<String> {
+            let analyses = lexicon.analyze(form);
+            analyses.into_iter().map(|x| fmt(x.lemma, x.info, x.case, x.number)).collect()
+        };
+
+        assert_eq!(analyze("бойцами"), ["боец мо 5*b INS PL"]);
+        assert_eq!(analyze("стола"), ["стол м 1b GEN SG"]);
+        assert_eq!(analyze("руки"), [
+            "рука ж 3f′ GEN SG",
+            "рука ж 3f′ NOM PL",
+            "рука ж 3f′ ACC PL",
+        ]);
+        assert_eq!(analyze("окон"), ["окно с 1*d GEN PL"]);
+        assert_eq!(analyze("Ежа́"), ["ёж мо 4b GEN SG", "ёж мо 4b ACC SG"]);
+        assert_eq!(analyze("ножницами"), ["ножницы мн. <ж 5a> INS PL"]);
+        assert_eq!(analyze("столовую"), ["столовая ж <п 1a> ACC SG"]);
+        assert_eq!(analyze("кофе").len(), 12);
+        assert_eq!(analyze("котятами"), ["котёнок мо 3°a INS PL"]);
+        assert_eq!(analyze("времени"), [
+            "время с 8°c GEN SG",
+            "время с 8°c DAT SG",
+            "время с 8°c PRP SG",
+        ]);
+        assert_eq!(analyze("лесу"), ["лес м 1c, П2 (в) DAT SG", "лес м 1c, П2 (в) LOC SG"]);
+
+        // Words that aren't in the lexicon
+        assert_eq!(analyze("стулом"), [] as [String; 0]);
+        assert_eq!(analyze("stol"), [] as [String; 0]);
+    }
+}
@@ -0,0 +1,263 @@
+//! Morphological analysis of inflected word forms.
+//!
+//! The crate's inflection only goes from a lemma to its forms. Analysis goes in reverse: given a
+//! word form, it returns the lemmas and grammatical categories that produce it. There are three
+//! ways to analyze a word form:
+//!
+//! - [`Lexicon::analyze`][crate::dictionary::Lexicon::analyze] strips the form's possible
+//!   endings, reverses the vowel alternations, and looks up the resulting lemmas in a dictionary.
+//! - [`guess`] does the same, but without a dictionary, guessing the lemmas' declensions from
+//!   their endings. Its results are ambiguous, but it works on any word.
+//! - An [`Analyzer`] indexes all forms of the nouns added to it, and looks up word forms
+//!   directly. It's the fastest, but it only knows the nouns that were added to it.
+//!
+//! # Examples
+//!
+//! ```
+//! use zaliznyak::{
+//!     analyzer,
+//!     categories::{CaseEx, Number},
+//!     dictionary::Lexicon,
+//! };
+//!
+//! let (lexicon, _) = Lexicon::parse("бое́ц мо 5*b\nстол м 1b\n");
+//!
+//! let analyses = lexicon.analyze("бойцами");
+//! assert_eq!(analyses[0].lemma, "боец");
+//! assert_eq!((analyses[0].case, analyses[0].number), (CaseEx::Instrumental, Number::Plural));
+//!
+//! // Without a dictionary, all of the possible lemmas are returned
+//! let candidates = analyzer::guess("бойцами");
+//! assert!(candidates.iter().any(|x| x.lemma == "боец"));
+//! ```
+//!
+//! ```
+//! use zaliznyak::{
+//!     analyzer::Analyzer,
+//!     categories::{CaseEx, Number},
+//! };
+//!
+//! let mut analyzer = Analyzer::new();
+//! analyzer.add_noun("бое́ц", "мо 5*b".parse().unwrap()).unwrap();
+//! analyzer.add_noun("стол", "м 1b".parse().unwrap()).unwrap();
+//!
+//! let analysis = analyzer.analyze("бойцами").next().unwrap();
+//! assert_eq!(analysis.lemma, "боец");
+//! assert_eq!((analysis.case, analysis.number), (CaseEx::Instrumental, Number::Plural));
+//! ```
+//!
+//! Analysis ignores letter case and stress marks, and doesn't distinguish 'е' and 'ё',
+//! since 'ё' is often written as 'е':
+//!
+//! ```
+//! use zaliznyak::analyzer::Analyzer;
+//!
+//! let mut analyzer = Analyzer::new();
+//! analyzer.add_noun("ёж", "мо 4b".parse().unwrap()).unwrap();
+//!
+//! assert_eq!(analyzer.analyze("Ежа́").count(), 2); // Р.ед. and В.ед.
+//! ```
+use crate::{
+    categories::{CaseEx, Number},
+    declension::InflectError,
+    noun::{NewNounError, Noun, NounInfo},
+    paradigm::NounParadigm,
+    word::WordBuf,
+};
+use std::collections::HashMap;
+use thiserror::Error;
+
+mod guess;
+
+pub use guess::*;
+
+/// A morphological analyzer, that maps inflected word forms of the added nouns to their lemmas
+/// and grammatical categories. Word forms of nouns, that weren't added, aren't analyzed; see
+/// [`guess`] and [`Lexicon::analyze`][crate::dictionary::Lexicon::analyze] for those. See the
+/// [module-level documentation][self] for more details.
+#[derive(Debug, Clone, Default)]
+pub struct Analyzer {
+    lemmas: Vec<(Box<str>, NounInfo)>,
+    forms: HashMap<Box<str>, Vec<FormEntry>>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct FormEntry {
+    lemma: usize,
+    case: CaseEx,
+    number: Number,
+}
+
+/// One of the possible analyses of a word form.
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub struct Analysis<'a> {
+    /// The lemma (dictionary form) of the word.
+    pub lemma: &'a str,
+    /// The lemma's noun info.
+    pub info: NounInfo,
+    /// The case of the word form.
+    pub case: CaseEx,
+    /// The number of the word form.
+    pub number: Number,
+}
+
+/// Error type for adding nouns to an [`Analyzer`].
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum AddNounError {
+    /// The noun couldn't be constructed from the lemma (see [`Noun::from_word`]).
+    #[error("invalid noun: {0}")]
+    InvalidNoun(NewNounError),
+    /// Some of the noun's forms couldn't be inflected (see [`Noun::try_paradigm`]).
+    #[error("error inflecting noun: {0}")]
+    InvalidDeclension(InflectError),
+}
+
+impl Analyzer {
+    /// Constructs a new, empty `Analyzer`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of lemmas added to this analyzer.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lemmas.len()
+    }
+    /// Returns `true` if no lemmas were added to this analyzer.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lemmas.is_empty()
+    }
+
+    /// Adds a noun to the analyzer, indexing all of its forms, including distinct partitive and
+    /// locative singular forms of nouns with Р2 and П2 marks (see [`NounFlags`][crate::noun::NounFlags]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AddNounError`] if the noun couldn't be constructed from the lemma, or its stem
+    /// doesn't fit its declension. The analyzer isn't modified in that case.
+    pub fn add_noun(&mut self, lemma: &str, info: NounInfo) -> Result<(), AddNounError> {
+        let noun = Noun::from_word(lemma, info).map_err(AddNounError::InvalidNoun)?;
+        let paradigm = noun.try_paradigm().map_err(AddNounError::InvalidDeclension)?;
+        let index = self.lemmas.len();
+
+        // The first form is nominative singular (or nominative plural, for pluralia tantum)
+        let lemma = paradigm.iter().next().map_or("", |(_, x)| x.as_str());
+        self.lemmas.push((lemma.into(), info));
+
+        for (case, number, word) in forms(&paradigm) {
+            let entry = FormEntry { lemma: index, case, number };
+            self.forms.entry(normalize(word.as_str())).or_default().push(entry);
+        }
+        Ok(())
+    }
+
+    /// Returns all analyses of the specified word form.
+    pub fn analyze(&self, form: &str) -> impl Iterator<Item = Analysis<'_>> {
+        let entries = self.forms.get(&*normalize(form)).map_or(&[][..], Vec::as_slice);
+
+        entries.iter().map(|entry| {
+            let (lemma, info) = &self.lemmas[entry.lemma];
+            Analysis { lemma, info: *info, case: entry.case, number: entry.number }
+        })
+    }
+}
+
+/// Returns all forms of a paradigm, including the distinct partitive and locative forms.
+fn forms(paradigm: &NounParadigm) -> impl Iterator<Item = (CaseEx, Number, &WordBuf)> {
+    let primary = paradigm.iter().map(|(x, word)| (CaseEx::from(x.case), x.number, word));
+    let secondary = [
+        paradigm.partitive().map(|word| (CaseEx::Partitive, Number::Singular, word)),
+        paradigm.locative().map(|word| (CaseEx::Locative, Number::Singular, word)),
+    ];
+    primary.chain(secondary.into_iter().flatten())
+}
+
+/// Lowercases the word, removes stress marks, and replaces 'ё' with 'е'.
+fn normalize(word: &str) -> Box<str> {
+    word.chars()
+        .filter(|&ch| ch != '\u{301}' && ch != '\u{300}')
+        .flat_map(char::to_lowercase)
+        .map(|ch| if ch == 'ё' { 'е' } else { ch })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(analyzer: &Analyzer, form: &str) -> Vec<String> {
+        let analyses = analyzer.analyze(form);
+        analyses
+            .map(|x| format!("{} {} {}", x.lemma, x.case.abbr_upper(), x.number.abbr_upper()))
+            .collect()
+    }
+
+    #[test]
+    fn analyze_nouns() {
+        let mut analyzer = Analyzer::new();
+        for (lemma, info) in [
+            ("бое́ц", "мо 5*b"),
+            ("стол", "м 1b"),
+            ("рука́", "ж 3f′"),
+            ("окно́", "с 1*d"),
+            ("ёж", "мо 4b"),
+            ("но́жницы", "мн. <ж 5a>"),
+            ("столо́вая", "ж <п 1a>"),
+            ("ко́фе", "м 0"),
+            ("лес", "м 1c, П2 (в)"),
+            ("ча́й", "м 6c, Р2"),
+        ] {
+            analyzer.add_noun(lemma, info.parse().unwrap()).unwrap();
+        }
+        assert_eq!(analyzer.len(), 10);
+
+        assert_eq!(analyze(&analyzer, "бойцами"), ["боец INS PL"]);
+        assert_eq!(analyze(&analyzer, "бойцов"), ["боец GEN PL", "боец ACC PL"]);
+        assert_eq!(analyze(&analyzer, "стол"), ["стол NOM SG", "стол ACC SG"]);
+        assert_eq!(analyze(&analyzer, "руки"), ["рука GEN SG", "рука NOM PL", "рука ACC PL"]);
+        assert_eq!(analyze(&analyzer, "окон"), ["окно GEN PL"]);
+        assert_eq!(analyze(&analyzer, "ежу"), ["ёж DAT SG"]);
+        assert_eq!(analyze(&analyzer, "ножницами"), ["ножницы INS PL"]);
+        assert_eq!(analyze(&analyzer, "столовой"), [
+            "столовая GEN SG",
+            "столовая DAT SG",
+            "столовая INS SG",
+            "столовая PRP SG",
+        ]);
+        assert_eq!(analyze(&analyzer, "кофе").len(), 12);
+        assert_eq!(analyze(&analyzer, "лесу"), ["лес DAT SG", "лес LOC SG"]);
+        assert_eq!(analyze(&analyzer, "чаю"), ["чай DAT SG", "чай PRT SG"]);
+
+        // Letter case and stress marks are ignored
+        assert_eq!(analyze(&analyzer, "Столо́м"), ["стол INS SG"]);
+        // Unknown forms
+        assert!(analyze(&analyzer, "стулом").is_empty());
+    }
+
+    #[test]
+    fn add_errors() {
+        let mut analyzer = Analyzer::new();
+        let info: NounInfo = "м 1a".parse().unwrap();
+
+        assert!(analyzer.is_empty());
+        let invalid_stem = Err(AddNounError::InvalidNoun(NewNounError::InvalidStem));
+        assert_eq!(analyzer.add_noun("stol", info), invalid_stem);
+        assert_eq!(analyzer.add_noun("", info), invalid_stem);
+        assert_eq!(analyzer.add_noun("столб", "м 1a".parse().unwrap()), Ok(()));
+        // The stem type of words ending in 'ъ' can't be identified
+        assert_eq!(analyzer.add_noun("сту́лъ", info), invalid_stem);
+
+        // ° alternation isn't defined for stems ending in '-ол'
+        let result = analyzer.add_noun("стол", "м 1°b".parse().unwrap());
+        assert_eq!(
+            result,
+            Err(AddNounError::InvalidDeclension(InflectError::InvalidUniqueAlternation))
+        );
+        assert_eq!(analyzer.len(), 1);
+        assert!(analyzer.analyze("стол").next().is_none());
+    }
+}
@@ -58,25 +58,13 @@
 //! the enums: [`DeclInfo`] for declension (nouns, adjectives, pronouns) and [`ConjInfo`] for
 //! conjugation (verbs).
 //!
-//! ```ignore
-//! // FIXME: When Adjective parsing is implemented, fix this example
-//!
+//! ```
 //! use zaliznyak::{
-//!     adjective::{Adjective, AdjectiveFlags, AdjectiveInfo, AdjectiveKind},
+//!     adjective::Adjective,
 //!     categories::{Animacy, Case, DeclInfo, Gender, Number},
-//!     declension::{AdjectiveDeclension, AdjectiveStemType, Declension, DeclensionFlags},
-//!     stress::AdjectiveStress,
 //! };
 //!
-//! let adj = Adjective::from_word("надёжный", AdjectiveInfo {
-//!     kind: AdjectiveKind::Regular,
-//!     flags: AdjectiveFlags::empty(),
-//!     declension: Some(Declension::Adjective(AdjectiveDeclension {
-//!         stem_type: AdjectiveStemType::Type1,
-//!         stress: AdjectiveStress::A,
-//!         flags: DeclensionFlags::STAR,
-//!     })),
-//! }).unwrap();
+//! let adj = Adjective::from_word("надёжный", "п 1*a".parse().unwrap()).unwrap();
 //!
 //! let info = DeclInfo {
 //!     case: Case::Instrumental,
@@ -88,6 +76,9 @@
 //! assert_eq!(adj.inflect(info).as_str(), "надёжными");
 //! ```
 //!
+//! To inflect adjectives together with the noun they refer to, without constructing the
+//! [`DeclInfo`] by hand, see [`NounPhrase`][crate::phrase::NounPhrase].
+//!
 //! [`Singular`]: Number::Singular
 //! [`Plural`]: Number::Plural
 //! [`Nominative`]: Case::Nominative
@@ -288,3 +279,10 @@ impl Animacy {
 impl Number {
     pub const VALUES: [Self; 2] = [Self::Singular, Self::Plural];
 }
+
+impl Tense {
+    pub const VALUES: [Self; 2] = [Self::Present, Self::Past];
+}
+impl Person {
+    pub const VALUES: [Self; 3] = [Self::First, Self::Second, Self::Third];
+}
@@ -0,0 +1,113 @@
+use crate::conjugation::ParseConjugationError;
+
+/// A verb's conjugation class, 1 through 16.
+/// [See the dictionary for more details](https://gramdict.ru/conjugation#classes).
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum ConjugationClass {
+    /// Class 1: делать, читать, краснеть (дела-ю, дела-л).
+    Class1,
+    /// Class 2: советовать, рисовать, воевать (советуj-у, советова-л).
+    Class2,
+    /// Class 3: крикнуть, толкнуть, сохнуть (крикн-у, крикну-л).
+    Class3,
+    /// Class 4: строить, просить, говорить (стро-ю, строи-л). Second conjugation.
+    Class4,
+    /// Class 5: смотреть, спать, держать (смотр-ю, смотре-л). Second conjugation.
+    Class5,
+    /// Class 6: писать, сеять, плакать (пиш-у, писа-л).
+    Class6,
+    /// Class 7: нести, вести, лезть (нес-у, нёс).
+    Class7,
+    /// Class 8: печь, мочь, беречь (пек-у, пёк).
+    Class8,
+    /// Class 9: тереть, умереть, запереть (тр-у, тёр).
+    Class9,
+    /// Class 10: колоть, пороть, бороться (кол-ю, коло-л).
+    Class10,
+    /// Class 11: бить, пить, шить (бь-ю, би-л).
+    Class11,
+    /// Class 12: мыть, дуть, гнить (мо-ю, мы-л).
+    Class12,
+    /// Class 13: давать, узнавать, вставать (да-ю, дава-л).
+    Class13,
+    /// Class 14: жать, мять, начать (жм-у, жа-л).
+    Class14,
+    /// Class 15: стать, деть, одеть (стан-у, ста-л).
+    Class15,
+    /// Class 16: жить, плыть, слыть (жив-у, жи-л).
+    Class16,
+}
+
+impl ConjugationClass {
+    /// Converts a number to a [`ConjugationClass`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::conjugation::ConjugationClass;
+    ///
+    /// assert_eq!(ConjugationClass::from_number(0), None);
+    /// assert_eq!(ConjugationClass::from_number(4), Some(ConjugationClass::Class4));
+    /// assert_eq!(ConjugationClass::from_number(16), Some(ConjugationClass::Class16));
+    /// assert_eq!(ConjugationClass::from_number(17), None);
+    /// ```
+    pub const fn from_number(number: u8) -> Option<Self> {
+        Some(match number {
+            1 => Self::Class1,
+            2 => Self::Class2,
+            3 => Self::Class3,
+            4 => Self::Class4,
+            5 => Self::Class5,
+            6 => Self::Class6,
+            7 => Self::Class7,
+            8 => Self::Class8,
+            9 => Self::Class9,
+            10 => Self::Class10,
+            11 => Self::Class11,
+            12 => Self::Class12,
+            13 => Self::Class13,
+            14 => Self::Class14,
+            15 => Self::Class15,
+            16 => Self::Class16,
+            _ => return None,
+        })
+    }
+    /// Converts this conjugation class to its corresponding number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::conjugation::ConjugationClass;
+    ///
+    /// assert_eq!(ConjugationClass::Class1.to_number(), 1);
+    /// assert_eq!(ConjugationClass::Class6.to_number(), 6);
+    /// assert_eq!(ConjugationClass::Class14.to_number(), 14);
+    /// ```
+    pub const fn to_number(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Returns `true` if verbs of this class follow the second conjugation in the present tense
+    /// (-ишь, -ит, -им, -ите, -ат/-ят). Only classes 4 and 5 do.
+    pub const fn is_second_conjugation(self) -> bool {
+        matches!(self, Self::Class4 | Self::Class5)
+    }
+}
+
+impl std::fmt::Display for ConjugationClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.to_number().fmt(f)
+    }
+}
+impl const std::str::FromStr for ConjugationClass {
+    type Err = ParseConjugationError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = match s.as_bytes() {
+            [d @ b'1'..=b'9'] => d - b'0',
+            [b'1', d @ b'0'..=b'9'] => 10 + (d - b'0'),
+            _ => return Err(Self::Err::InvalidClass),
+        };
+        Self::from_number(number).ok_or(Self::Err::InvalidClass)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{
+    categories::{Gender, Number, Person},
+    conjugation::Conjugation,
+};
+
+impl Conjugation {
+    /// Returns the present tense ending for the specified form. The `soft` parameter determines
+    /// the vowel in 1st person singular and 3rd person plural forms (-у/-ю, -ут/-ют, -ат/-ят),
+    /// and `stressed` determines the first conjugation's vowel in other forms (-ешь/-ёшь).
+    pub(crate) const fn find_present_ending(
+        self,
+        number: Number,
+        person: Person,
+        soft: bool,
+        stressed: bool,
+    ) -> &'static str {
+        match (number, person) {
+            (Number::Singular, Person::First) => return if soft { "ю" } else { "у" },
+            (Number::Plural, Person::Third) => {
+                return match (self.class.is_second_conjugation(), soft) {
+                    (false, false) => "ут",
+                    (false, true) => "ют",
+                    (true, false) => "ат",
+                    (true, true) => "ят",
+                };
+            },
+            _ => {},
+        };
+
+        if self.class.is_second_conjugation() {
+            match (number, person) {
+                (Number::Singular, Person::Second) => "ишь",
+                (Number::Singular, _) => "ит",
+                (_, Person::First) => "им",
+                _ => "ите",
+            }
+        } else if stressed {
+            match (number, person) {
+                (Number::Singular, Person::Second) => "ёшь",
+                (Number::Singular, _) => "ёт",
+                (_, Person::First) => "ём",
+                _ => "ёте",
+            }
+        } else {
+            match (number, person) {
+                (Number::Singular, Person::Second) => "ешь",
+                (Number::Singular, _) => "ет",
+                (_, Person::First) => "ем",
+                _ => "ете",
+            }
+        }
+    }
+
+    /// Returns the past tense ending for the specified form. The suffix -л- is considered
+    /// a part of the stem, and is not included in the ending.
+    pub(crate) const fn find_past_ending(number: Number, gender: Gender) -> &'static str {
+        match (number, gender) {
+            (Number::Plural, _) => "и",
+            (_, Gender::Masculine) => "",
+            (_, Gender::Neuter) => "о",
+            (_, Gender::Feminine) => "а",
+        }
+    }
+}
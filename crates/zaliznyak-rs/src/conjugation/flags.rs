@@ -0,0 +1,82 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// A set of conjugation flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::conjugation::ConjugationFlags;
+    ///
+    /// let flags: ConjugationFlags = "°".parse().unwrap();
+    /// assert_eq!(flags, ConjugationFlags::CIRCLE);
+    ///
+    /// let flags: ConjugationFlags = "°, ё".parse().unwrap();
+    /// assert_eq!(flags, ConjugationFlags::CIRCLE | ConjugationFlags::ALTERNATING_YO);
+    /// ```
+    #[derive(Debug, Copy, Eq)]
+    #[derive_const(Clone)]
+    pub struct ConjugationFlags: u8 {
+        /// The ° flag, indicating a non-standard alternation in the stem, e.g. брать (6°b/c) ---
+        /// беру, звать (6°b/c) --- зову, сохнуть (3°a) --- сох.
+        /// [See the dictionary for more details](https://gramdict.ru/conjugation#superscript-circle).
+        const CIRCLE = 1 << 0;
+        /// The * flag, indicating a vowel alternation in the stem.
+        /// [See the dictionary for more details](https://gramdict.ru/conjugation#star).
+        const STAR = 1 << 1;
+        /// The ё flag, indicating a 'е'/'ё' alternation in the stem, e.g. нести (7b/b, ё) --- нёс.
+        /// [See the dictionary for more details](https://gramdict.ru/conjugation#yo).
+        const ALTERNATING_YO = 1 << 2;
+        /// The ⑨ flag, indicating that the imperative is difficult to form, or is not used,
+        /// e.g. мочь (8c/b(-г-)⑨) --- моги.
+        const DIFFICULT_IMPERATIVE = 1 << 3;
+    }
+}
+
+impl ConjugationFlags {
+    /// Returns `true` if this contains the ° flag.
+    pub const fn has_circle(self) -> bool {
+        self.intersects(Self::CIRCLE)
+    }
+    /// Returns `true` if this contains the * flag.
+    pub const fn has_star(self) -> bool {
+        self.intersects(Self::STAR)
+    }
+    /// Returns `true` if this contains the ё flag.
+    pub const fn has_alternating_yo(self) -> bool {
+        self.intersects(Self::ALTERNATING_YO)
+    }
+    /// Returns `true` if this contains the ⑨ flag.
+    pub const fn has_difficult_imperative(self) -> bool {
+        self.intersects(Self::DIFFICULT_IMPERATIVE)
+    }
+
+    const LEADING: Self = Self::CIRCLE.union(Self::STAR);
+    const TRAILING: Self = Self::DIFFICULT_IMPERATIVE.union(Self::ALTERNATING_YO);
+
+    /// Returns `true` if this contains any leading flags: ° or *.
+    pub const fn has_any_leading_flags(self) -> bool {
+        self.intersects(Self::LEADING)
+    }
+    /// Returns `true` if this contains any trailing flags: ⑨ or ё.
+    pub const fn has_any_trailing_flags(self) -> bool {
+        self.intersects(Self::TRAILING)
+    }
+}
+
+// FIXME(const-hack): Replace these with #[derive_const], once bitflags crate supports it.
+impl const Default for ConjugationFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+impl const PartialEq for ConjugationFlags {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+impl std::hash::Hash for ConjugationFlags {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_u8(self.bits());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    conjugation::{Conjugation, ConjugationFlags},
+    stress::{AnyDualStress, DUAL_STRESS_MAX_LEN},
+    util::UnsafeBuf,
+};
+
+/// The maximum byte length of a formatted [`ConjugationFlags`].
+///
+/// Longest form: °*⑨, ё (10 bytes, 6 chars)
+pub const CONJUGATION_FLAGS_MAX_LEN: usize = 10;
+
+impl ConjugationFlags {
+    #[inline]
+    pub(crate) const fn fmt_leading_to(self, dst: &mut [u8; 3]) -> &mut str {
+        let mut dst = UnsafeBuf::new(dst);
+
+        if self.has_circle() {
+            dst.push('°');
+        }
+        if self.has_star() {
+            dst.push('*');
+        }
+        dst.finish()
+    }
+    #[inline]
+    pub(crate) const fn fmt_trailing_to(self, dst: &mut [u8; 7]) -> &mut str {
+        let mut dst = UnsafeBuf::new(dst);
+
+        if self.has_difficult_imperative() {
+            dst.push('⑨');
+        }
+        if self.has_alternating_yo() {
+            dst.push_str(", ё");
+        }
+        dst.finish()
+    }
+    /// Formats these conjugation flags as UTF-8 into the provided byte buffer, and then returns
+    /// a subslice of the buffer that contains the encoded string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::conjugation::ConjugationFlags;
+    ///
+    /// let x = ConjugationFlags::CIRCLE | ConjugationFlags::ALTERNATING_YO;
+    /// assert_eq!(x.fmt_to(&mut [0; _]), "°, ё");
+    /// ```
+    pub const fn fmt_to(self, dst: &mut [u8; CONJUGATION_FLAGS_MAX_LEN]) -> &mut str {
+        let mut dst = UnsafeBuf::new(dst);
+        dst.push_fmt2(self, Self::fmt_leading_to);
+        dst.push_fmt2(self, Self::fmt_trailing_to);
+        dst.finish()
+    }
+}
+
+/// The maximum byte length of a formatted [`Conjugation`].
+///
+/// Longest form: 14°*c′/c″(-н-)⑨, ё (27 bytes, 19 chars)
+pub const CONJUGATION_MAX_LEN: usize = 2 + CONJUGATION_FLAGS_MAX_LEN + DUAL_STRESS_MAX_LEN + 6;
+
+impl Conjugation {
+    /// Formats this conjugation as UTF-8 into the provided byte buffer, and then returns a subslice
+    /// of the buffer that contains the encoded string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::conjugation::Conjugation;
+    ///
+    /// let conj: Conjugation = "7b/b(-д-), ё".parse().unwrap();
+    ///
+    /// assert_eq!(conj.fmt_to(&mut [0; _]), "7b/b(-д-), ё");
+    /// ```
+    pub const fn fmt_to(self, dst: &mut [u8; CONJUGATION_MAX_LEN]) -> &mut str {
+        let mut dst = UnsafeBuf::new(dst);
+
+        let number = self.class.to_number();
+        if number >= 10 {
+            dst.push('1');
+        }
+        dst.push((b'0' + number % 10) as char);
+
+        dst.push_fmt2(self.flags, ConjugationFlags::fmt_leading_to);
+        dst.push_fmt2(AnyDualStress::from(self.stress).abbr_verb(), AnyDualStress::fmt_to);
+
+        if let Some(consonant) = self.stem_consonant {
+            dst.push_str("(-");
+            dst.push_str(consonant.as_str());
+            dst.push_str("-)");
+        }
+
+        dst.push_fmt2(self.flags, ConjugationFlags::fmt_trailing_to);
+
+        dst.finish()
+    }
+}
+
+impl std::fmt::Display for ConjugationFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.fmt_to(&mut [0; _]).fmt(f)
+    }
+}
+impl std::fmt::Display for Conjugation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.fmt_to(&mut [0; _]).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConjugationFlags as CF, *};
+    use crate::{conjugation::ConjugationClass::*, stress::VerbStress as S, word::Utf8Letter};
+
+    #[test]
+    fn fmt_flags() {
+        assert_eq!(CF::empty().to_string(), "");
+        assert_eq!(CF::CIRCLE.to_string(), "°");
+        assert_eq!(CF::STAR.to_string(), "*");
+        assert_eq!(CF::ALTERNATING_YO.to_string(), ", ё");
+        assert_eq!(CF::DIFFICULT_IMPERATIVE.to_string(), "⑨");
+        assert_eq!(CF::all().to_string(), "°*⑨, ё");
+    }
+
+    #[test]
+    fn fmt_conjugation() {
+        let assert_fmt = |class, stress, flags, stem_consonant, expected: &str| {
+            let conj = Conjugation { class, stress, flags, stem_consonant };
+            assert_eq!(conj.to_string(), expected);
+        };
+
+        assert_fmt(Class1, S::A_A, CF::empty(), None, "1a");
+        assert_fmt(Class4, S::C_A, CF::empty(), None, "4c");
+        assert_fmt(Class6, S::B_C, CF::CIRCLE, None, "6°b/c");
+        assert_fmt(Class7, S::B_B, CF::ALTERNATING_YO, Some(Utf8Letter::Д), "7b/b(-д-), ё");
+        assert_fmt(Class14, S::B_Cpp, CF::empty(), Some(Utf8Letter::Н), "14b/c″(-н-)");
+        assert_fmt(Class16, S::B_C, CF::empty(), None, "16b/c");
+        assert_fmt(Class8, S::C_B, CF::DIFFICULT_IMPERATIVE, Some(Utf8Letter::Г), "8c/b(-г-)⑨");
+        assert_fmt(Class14, S::Cp_Cpp, CF::all(), Some(Utf8Letter::Н), "14°*c′/c″(-н-)⑨, ё");
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    conjugation::{Conjugation, ConjugationClass, ConjugationFlags},
+    stress::{AnyDualStress, ParseStressError},
+    util::{PartialFromStr, UnsafeParser},
+    word::Utf8Letter,
+};
+use thiserror::Error;
+
+/// Error type for parsing conjugations.
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum ParseConjugationError {
+    /// The first characters are not a valid conjugation class number.
+    #[error("invalid characters in place of conjugation class")]
+    InvalidClass,
+    /// Error parsing stress. See [`ParseStressError`].
+    #[error("error parsing stress: {0}")]
+    InvalidStress(ParseStressError),
+    /// The parsed stress schema is not a valid verb stress schema.
+    #[error("stress not compatible with verbs")]
+    IncompatibleStress,
+    /// The present tense stem consonant, `(-д-)`, is not a single consonant.
+    #[error("invalid present tense stem consonant")]
+    InvalidStemConsonant,
+    /// Invalid format.
+    #[error("invalid format")]
+    Invalid,
+}
+
+type Error = ParseConjugationError;
+
+impl ConjugationFlags {
+    #[inline]
+    pub(crate) const fn partial_from_str_leading(flags: &mut Self, parser: &mut UnsafeParser) {
+        if parser.skip('°') {
+            *flags = flags.union(Self::CIRCLE);
+        }
+        if parser.skip('*') {
+            *flags = flags.union(Self::STAR);
+        }
+    }
+    #[inline]
+    pub(crate) const fn partial_from_str_trailing(flags: &mut Self, parser: &mut UnsafeParser) {
+        if parser.skip('⑨') {
+            *flags = flags.union(Self::DIFFICULT_IMPERATIVE);
+        }
+        if parser.skip_str(", ё") {
+            *flags = flags.union(Self::ALTERNATING_YO);
+        }
+    }
+}
+impl const PartialFromStr for ConjugationFlags {
+    fn partial_from_str(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        let mut flags = Self::empty();
+        Self::partial_from_str_leading(&mut flags, parser);
+        Self::partial_from_str_trailing(&mut flags, parser);
+        Ok(flags)
+    }
+}
+
+impl ConjugationClass {
+    #[inline]
+    const fn partial_from_str(parser: &mut UnsafeParser) -> Result<Self, Error> {
+        let number = match parser.read_one() {
+            Some(b'1') => match parser.peek_one() {
+                Some(d @ b'0'..=b'9') => {
+                    let d = *d;
+                    parser.forward(1);
+                    10 + (d - b'0')
+                },
+                _ => 1,
+            },
+            Some(d @ b'2'..=b'9') => d - b'0',
+            _ => return Err(Error::InvalidClass),
+        };
+        Self::from_number(number).ok_or(Error::InvalidClass)
+    }
+}
+
+const fn parse_stem_consonant(parser: &mut UnsafeParser) -> Result<Option<Utf8Letter>, Error> {
+    if !parser.skip_str("(-") {
+        return Ok(None);
+    }
+    let Some(letter) = parser.peek_letter() else { return Err(Error::InvalidStemConsonant) };
+    parser.forward(2);
+
+    if !letter.is_consonant() || !parser.skip_str("-)") {
+        return Err(Error::InvalidStemConsonant);
+    }
+    Ok(Some(letter))
+}
+
+impl const PartialFromStr for Conjugation {
+    fn partial_from_str(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        let class = ConjugationClass::partial_from_str(parser)?;
+
+        let mut flags = ConjugationFlags::empty();
+
+        ConjugationFlags::partial_from_str_leading(&mut flags, parser);
+
+        let stress = AnyDualStress::partial_from_str(parser).map_err(Error::InvalidStress)?;
+        let stress = stress.try_into().ok().ok_or(Error::IncompatibleStress)?;
+
+        let stem_consonant = parse_stem_consonant(parser)?;
+
+        ConjugationFlags::partial_from_str_trailing(&mut flags, parser);
+
+        Ok(Self { class, stress, flags, stem_consonant })
+    }
+}
+
+impl const std::str::FromStr for ConjugationFlags {
+    type Err = ParseConjugationError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_or_err(s, Self::Err::Invalid)
+    }
+}
+impl const std::str::FromStr for Conjugation {
+    type Err = ParseConjugationError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_or_err(s, Self::Err::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConjugationFlags as CF, *};
+    use crate::{conjugation::ConjugationClass::*, stress::VerbStress as S, word::Utf8Letter};
+
+    #[test]
+    fn parse_flags() {
+        let assert_ok = |s: &str, flags| assert_eq!(s.parse(), Ok(flags));
+
+        assert_ok("", CF::empty());
+        assert_ok("°", CF::CIRCLE);
+        assert_ok("*", CF::STAR);
+        assert_ok(", ё", CF::ALTERNATING_YO);
+        assert_ok("⑨", CF::DIFFICULT_IMPERATIVE);
+        assert_ok("°*⑨, ё", CF::all());
+
+        assert_eq!("*°".parse::<CF>(), Err(Error::Invalid));
+        assert_eq!(", ё⑨".parse::<CF>(), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn parse_conjugation() {
+        let assert_ok = |s: &str, class, stress, flags, stem_consonant| {
+            assert_eq!(s.parse(), Ok(Conjugation { class, stress, flags, stem_consonant }))
+        };
+        let assert_err = |s: &str, err| assert_eq!(s.parse::<Conjugation>(), Err(err));
+
+        assert_ok("1a", Class1, S::A_A, CF::empty(), None);
+        assert_ok("3°a", Class3, S::A_A, CF::CIRCLE, None);
+        assert_ok("4c", Class4, S::C_A, CF::empty(), None);
+        assert_ok("6°b/c", Class6, S::B_C, CF::CIRCLE, None);
+        assert_ok("7b/b(-д-), ё", Class7, S::B_B, CF::ALTERNATING_YO, Some(Utf8Letter::Д));
+        assert_ok("10c", Class10, S::C_A, CF::empty(), None);
+        assert_ok("14b/c''(-н-)", Class14, S::B_Cpp, CF::empty(), Some(Utf8Letter::Н));
+        assert_ok("16b/c", Class16, S::B_C, CF::empty(), None);
+        let flags = CF::DIFFICULT_IMPERATIVE;
+        assert_ok("8c/b(-г-)⑨", Class8, S::C_B, flags, Some(Utf8Letter::Г));
+
+        assert_err("", Error::InvalidClass);
+        assert_err("0a", Error::InvalidClass);
+        assert_err("17a", Error::InvalidClass);
+        assert_err("z", Error::InvalidClass);
+        assert_err("4", Error::InvalidStress(ParseStressError::InvalidLetter));
+        assert_err("4d", Error::IncompatibleStress);
+        assert_err("4b/e", Error::IncompatibleStress);
+        assert_err("7b(-а-)", Error::InvalidStemConsonant);
+        assert_err("7b(-д)", Error::InvalidStemConsonant);
+        assert_err("7b(-)", Error::InvalidStemConsonant);
+    }
+}
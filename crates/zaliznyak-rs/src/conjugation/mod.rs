@@ -0,0 +1,87 @@
+//! Verb conjugation.
+//!
+//! This module provides types containing information necessary for standard conjugation of verbs:
+//! conjugation classes, stress schemas (see [`stress`][crate::stress] module), and conjugation
+//! flags (see [`ConjugationFlags`]).
+//!
+//! # Conjugations
+//!
+//! Conjugations can be constructed in the same ways as declensions:
+//!
+//! ```
+//! use zaliznyak::{
+//!     conjugation::{Conjugation, ConjugationClass, ConjugationFlags},
+//!     stress::VerbStress,
+//!     word::Utf8Letter,
+//! };
+//!
+//! // The simplest way is to parse them from strings:
+//! let conj: Conjugation = "7b/b(-д-), ё".parse().unwrap();
+//!
+//! // Or you can just construct one explicitly:
+//! let conj = Conjugation {
+//!     class: ConjugationClass::Class7,
+//!     stress: VerbStress::B_B,
+//!     flags: ConjugationFlags::ALTERNATING_YO,
+//!     stem_consonant: Some(Utf8Letter::Д),
+//! };
+//! ```
+//!
+//! # Parsing and formatting
+//!
+//! Like declensions, conjugations also accept ASCII stress primes (`'` --- `′`, `"` and `''` ---
+//! `″`), but are always formatted using Unicode characters:
+//!
+//! ```
+//! use zaliznyak::conjugation::Conjugation;
+//!
+//! let conj: Conjugation = "14b/c''(-н-)".parse().unwrap();
+//! assert_eq!(conj.to_string(), "14b/c″(-н-)");
+//! ```
+
+use crate::{stress::VerbStress, word::Utf8Letter};
+
+mod classes;
+mod endings;
+mod flags;
+mod fmt;
+mod from_str;
+
+pub use classes::*;
+pub use flags::*;
+pub use fmt::*;
+pub use from_str::*;
+
+/// A verb conjugation.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     conjugation::{Conjugation, ConjugationClass, ConjugationFlags},
+///     stress::VerbStress,
+/// };
+///
+/// let conj: Conjugation = "6°b/c".parse().unwrap();
+///
+/// assert_eq!(conj, Conjugation {
+///     class: ConjugationClass::Class6,
+///     stress: VerbStress::B_C,
+///     flags: ConjugationFlags::CIRCLE,
+///     stem_consonant: None,
+/// });
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub struct Conjugation {
+    /// The conjugation's class.
+    pub class: ConjugationClass,
+    /// The conjugation's stress schema.
+    pub stress: VerbStress,
+    /// The conjugation's flags.
+    pub flags: ConjugationFlags,
+    /// The last consonant of the present tense stem, if it's not evident from the infinitive.
+    /// Used only by classes 7, 8 and 14, e.g. вести (7b/b(-д-)) --- веду, печь (8b/b(-к-)) ---
+    /// пеку, жать (14b(-м-)) --- жму.
+    pub stem_consonant: Option<Utf8Letter>,
+}
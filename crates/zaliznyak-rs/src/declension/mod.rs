@@ -80,6 +80,7 @@
 //! ```
 
 use crate::stress::{AdjectiveStress, AnyDualStress, NounStress, PronounStress};
+use thiserror::Error;
 
 mod endings;
 mod endings_tables;
@@ -299,3 +300,39 @@ impl const TryFrom<Declension> for AdjectiveDeclension {
         value.as_adjective().ok_or(())
     }
 }
+
+/// Error type for inflecting words with inconsistent declension data.
+///
+/// Returned by the fallible `try_inflect` methods, such as
+/// [`Noun::try_inflect`][crate::noun::Noun::try_inflect], when the word's stem or declension
+/// can't produce the requested form.
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum InflectError {
+    /// The declension type can't be used with this part of speech (e.g. a noun with a pronoun
+    /// declension, or an adjective with a noun declension).
+    #[error("declension type not compatible with the word")]
+    IncompatibleDeclension,
+    /// The stem has no vowel to receive the stress or to alternate (*).
+    #[error("no vowel in the stem")]
+    NoVowelInStem,
+    /// The stem doesn't allow the vowel alternation (*) specified by the declension.
+    #[error("invalid vowel alternation")]
+    InvalidVowelAlternation,
+    /// The stem has no 'е' that could alternate with 'ё', specified by the declension.
+    #[error("no alternating 'е' in the stem")]
+    NoAlternatingYe,
+    /// The stem doesn't match any of the known unique alternations (°).
+    #[error("invalid unique stem alternation")]
+    InvalidUniqueAlternation,
+}
+
+/// Unwraps the result of a fallible inflection, panicking with the error's message. Used by the
+/// panicking counterparts of the `try_` methods.
+#[track_caller]
+pub(crate) fn unwrap_inflected<T>(result: Result<T, InflectError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(err) => panic!("{err}"),
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    declension::Declension,
     util::enum_conversion,
     word::{Utf8Letter, WordBuf},
 };
@@ -291,3 +292,41 @@ impl AdjectiveStemType {
         }
     }
 }
+
+impl Declension {
+    /// Identifies the stem of a word in nominative form using this declension's type, trims it,
+    /// and checks whether its stem type matches the declension's. Returns `None` if the stem could
+    /// not be identified, or `Some((matches, is_reflexive))` otherwise.
+    pub(crate) fn identify_trim(self, word: &mut WordBuf) -> Option<(bool, bool)> {
+        let (stem_len, matches, is_reflexive) = self.identify(word.as_letters())?;
+        word.set_stem_len(stem_len);
+        Some((matches, is_reflexive))
+    }
+    /// Identifies the stem of a word in nominative form using this declension's type, and checks
+    /// whether its stem type matches the declension's. Returns `None` if the stem could not be
+    /// identified, or `Some((stem_len, matches, is_reflexive))` otherwise.
+    pub(crate) fn identify(self, word: &[Utf8Letter]) -> Option<(usize, bool, bool)> {
+        let (stem_len, identified, is_reflexive) = match self {
+            Self::Noun(_) => {
+                let (stem, ty) = NounStemType::identify(word)?;
+                (stem.len(), ty.into(), false)
+            },
+            Self::Pronoun(_) => {
+                let (stem, ty) = PronounStemType::identify(word)?;
+                (stem.len(), ty.into(), false)
+            },
+            Self::Adjective(_) => {
+                let (stem, ty, is_reflexive) = AdjectiveStemType::identify(word)?;
+                (stem.len(), ty.into(), is_reflexive)
+            },
+        };
+
+        // Stem type 8 can't be identified from letters, since its nominative forms look exactly
+        // like soft and sibilant stems' ones (путь, мать, дочь, рожь, имя).
+        let matches = match self.stem_type() {
+            AnyStemType::Type8 => matches!(identified, AnyStemType::Type2 | AnyStemType::Type4),
+            declared => declared == identified,
+        };
+        Some((stem_len, matches, is_reflexive))
+    }
+}
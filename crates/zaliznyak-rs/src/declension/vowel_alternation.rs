@@ -1,6 +1,6 @@
 use crate::{
     categories::{Case, DeclInfo, Gender, IntoNumber},
-    declension::{NounDeclension, NounStemType},
+    declension::{InflectError, NounDeclension, NounStemType},
     stress::NounStress,
     util::InflectionBuf,
     word::Utf8Letter,
@@ -30,7 +30,11 @@ enum B_Operation {
 }
 
 impl VowelAlternation {
-    pub fn prepare_noun(stem: &[Utf8Letter], decl: NounDeclension, gender: Gender) -> Option<Self> {
+    pub fn prepare_noun(
+        stem: &[Utf8Letter],
+        decl: NounDeclension,
+        gender: Gender,
+    ) -> Result<Option<Self>, InflectError> {
         if gender == Gender::Masculine
             || gender == Gender::Feminine && decl.stem_type == NounStemType::Type8
         {
@@ -39,7 +43,7 @@ impl VowelAlternation {
 
             // Find the alternating LAST vowel in stem
             let Some(found) = stem.iter().enumerate().rfind(|x| x.1.is_vowel()) else {
-                todo!("Handle absence of vowels in the stem?")
+                return Err(InflectError::NoVowelInStem);
             };
             let (vowel_pos, vowel) = found;
 
@@ -48,7 +52,7 @@ impl VowelAlternation {
                 Utf8Letter::О => None,
 
                 Utf8Letter::Е | Utf8Letter::Ё => {
-                    let preceding = stem.get(vowel_pos - 1).copied();
+                    let preceding = vowel_pos.checked_sub(1).and_then(|x| stem.get(x)).copied();
 
                     #[allow(unused_parens)]
                     if preceding.is_some_and(|x| x.is_vowel()) {
@@ -69,12 +73,10 @@ impl VowelAlternation {
                         None
                     }
                 },
-                _ => {
-                    todo!("Handle invalid vowel alternation")
-                },
+                _ => return Err(InflectError::InvalidVowelAlternation),
             };
 
-            return Some(Self::A(vowel_pos as _, replace));
+            return Ok(Some(Self::A(vowel_pos as _, replace)));
         }
 
         if matches!(gender, Gender::Neuter | Gender::Feminine) {
@@ -86,27 +88,30 @@ impl VowelAlternation {
             if decl.stem_type == NounStemType::Type2
                 && matches!(decl.stress, NounStress::B | NounStress::F)
             {
-                return None;
+                return Ok(None);
             }
             // If (2) flag changed the ending's gender, don't alternate the vowel,
             //   since it won't be consistent with the ending of different gender.
             if decl.flags.has_circled_two() {
-                return None;
+                return Ok(None);
             }
 
             // 1) stem type 6: stem's ending 'ь' is replaced with 'е' or 'и'.
             // E.g. лгунья (ж 6*a) - Р.мн. лгуний; статья (ж 6*b) - Р.мн. статей.
             if decl.stem_type == NounStemType::Type6 {
                 if let [.., Utf8Letter::Ь] = stem {
-                    return Some(Self::B(B_Operation::ReplaceLastWithI));
+                    return Ok(Some(Self::B(B_Operation::ReplaceLastWithI)));
                 }
                 // Alternations in stem type 6 happen only with 'ь'.
-                return None;
+                return Ok(None);
             }
 
             // At this point, stem type is in range 1..=5 (consonant-ending stems).
             // Stem type 6 was completely handled earlier, and 7* nouns don't exist.
             // So, it's safe to assume that the last stem char is a consonant.
+            if stem.len() < 2 {
+                return Err(InflectError::InvalidVowelAlternation);
+            }
             let last = stem.last().copied();
             let pre_last = stem.get(stem.len() - 2).copied();
 
@@ -114,9 +119,9 @@ impl VowelAlternation {
             // E.g. гайка (ж 3*a) - Р.мн. гаек; сальце (с 5*a) - Р.мн. салец.
             if let Some(Utf8Letter::Ь | Utf8Letter::Й) = pre_last {
                 if last == Some(Utf8Letter::Ц) {
-                    return Some(Self::B(B_Operation::ReplacePreLastWithYe));
+                    return Ok(Some(Self::B(B_Operation::ReplacePreLastWithYe)));
                 } else {
-                    return Some(Self::B(B_Operation::ReplacePreLastWithYeOrYo));
+                    return Ok(Some(Self::B(B_Operation::ReplacePreLastWithYeOrYo)));
                 };
             }
 
@@ -124,29 +129,29 @@ impl VowelAlternation {
 
             if let Some(Utf8Letter::К | Utf8Letter::Г | Utf8Letter::Х) = pre_last {
                 // 3)a) after 'к'/'г'/'х' insert 'о'
-                return Some(Self::B(B_Operation::InsertO));
+                return Ok(Some(Self::B(B_Operation::InsertO)));
             }
             if let Some(Utf8Letter::К | Utf8Letter::Г | Utf8Letter::Х) = last
                 && pre_last.is_some_and(|x| !x.is_sibilant())
             {
                 // 3)b) before 'к'/'г'/'х', but not after sibilant, insert 'о'
-                return Some(Self::B(B_Operation::InsertO));
+                return Ok(Some(Self::B(B_Operation::InsertO)));
             }
 
             // 3)c) if unstressed insert 'е', and if stressed - 'ё'
 
             if last == Some(Utf8Letter::Ц) {
                 // But after 'ц' only 'е'
-                return Some(Self::B(B_Operation::InsertYe));
+                return Ok(Some(Self::B(B_Operation::InsertYe)));
             }
             if pre_last.is_some_and(|x| x.is_hissing()) {
                 // And after hissing consonants 'о' instead of 'ё'
-                return Some(Self::B(B_Operation::InsertYeOrO));
+                return Ok(Some(Self::B(B_Operation::InsertYeOrO)));
             }
-            return Some(Self::B(B_Operation::InsertYeOrYo));
+            return Ok(Some(Self::B(B_Operation::InsertYeOrYo)));
         }
 
-        todo!("Invalid vowel alternation")
+        Err(InflectError::InvalidVowelAlternation)
     }
 
     pub fn apply(&self, decl: NounDeclension, info: DeclInfo, buf: &mut InflectionBuf) {
@@ -229,8 +234,13 @@ impl VowelAlternation {
 }
 
 impl NounDeclension {
-    pub(crate) fn apply_vowel_alternation(&self, info: DeclInfo, buf: &mut InflectionBuf) {
-        VowelAlternation::prepare_noun(buf.stem(), *self, info.gender)
+    pub(crate) fn apply_vowel_alternation(
+        &self,
+        info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
+        VowelAlternation::prepare_noun(buf.stem(), *self, info.gender)?
             .inspect(|x| x.apply(*self, info, buf));
+        Ok(())
     }
 }
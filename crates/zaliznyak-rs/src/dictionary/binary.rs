@@ -0,0 +1,573 @@
+use crate::{
+    adjective::{Adjective, AdjectiveFlags, AdjectiveInfo, AdjectiveKind},
+    categories::{Animacy, Gender, GenderEx, Number},
+    declension::{
+        AdjectiveDeclension, AdjectiveStemType, Declension, DeclensionFlags, NounDeclension,
+        NounStemType, PronounDeclension, PronounStemType,
+    },
+    dictionary::{Entry, Lexicon},
+    noun::{Noun, NounFlags, NounInfo},
+    pronoun::{Pronoun, PronounInfo},
+    stress::{AdjectiveStress, AnyStress},
+    word::{ByteLetter, Utf8Letter, Word},
+};
+use thiserror::Error;
+
+// Binary lexicon layout (all integers are little-endian u32):
+//
+// ┌———————————————┬———————┬——————————————————————┬—————————————————————┐
+// │ magic "ZLX\2" │ count │ record offsets × count │ records (any order) │
+// └———————————————┴———————┴——————————————————————┴—————————————————————┘
+//
+// The offsets are sorted by the records' lemmas, so that lookups can use binary search.
+// Every record is laid out as follows (all lengths are single bytes):
+//
+// ┌—————————┬———————┬—————┬——————┬——————————┬———————————┬—————————┬—————————————————┐
+// │ lem len │ lemma │ tag │ info │ stem len │ stress at │ letters │ letters (bytes) │
+// └—————————┴———————┴—————┴——————┴——————————┴———————————┴—————————┴—————————————————┘
+//
+// The lemma is stored as UTF-8, to be compared directly with the looked up string, and the
+// word's letters are stored as `ByteLetter`s. The info is packed into 5 bytes (see `pack_noun_info` etc.).
+
+const MAGIC: [u8; 4] = *b"ZLX\x02";
+const HEADER_LEN: usize = 8;
+const INFO_LEN: usize = 5;
+
+const TAG_NOUN: u8 = 0;
+const TAG_ADJECTIVE: u8 = 1;
+const TAG_ADJECTIVE_REFLEXIVE: u8 = 2;
+const TAG_PRONOUN: u8 = 3;
+
+/// Error type for reading binary lexicon data.
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum LexiconDataError {
+    /// The data doesn't start with the binary lexicon's magic bytes, or has a different version.
+    #[error("invalid binary lexicon header")]
+    InvalidHeader,
+    /// The data is too short to contain all of the record offsets.
+    #[error("unexpected end of binary lexicon data")]
+    UnexpectedEnd,
+    /// The record at the specified offset is truncated or malformed.
+    #[error("invalid record at offset {0}")]
+    InvalidRecord(usize),
+}
+
+/// Error type for serializing a lexicon with [`Lexicon::to_bytes`].
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum EncodeLexiconError {
+    /// The lemma of the entry at the specified index is longer than 255 bytes.
+    #[error("lemma of entry {0} is longer than 255 bytes")]
+    LemmaTooLong(usize),
+    /// The word of the entry at the specified index is longer than 255 letters.
+    #[error("word of entry {0} is longer than 255 letters")]
+    WordTooLong(usize),
+}
+
+/// A read-only view of a lexicon in the compact binary format (see [`Lexicon::to_bytes`]).
+///
+/// The view borrows the data and doesn't copy or deserialize it, so it can be constructed directly
+/// from a memory-mapped file. Entries are decoded only when they're looked up.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{CaseEx, Number},
+///     dictionary::{Entry, Lexicon, LexiconView},
+/// };
+///
+/// let (lexicon, _) = Lexicon::parse("сло́во с 1a\nбое́ц мо 5*b\n");
+/// let bytes = lexicon.to_bytes().unwrap();
+///
+/// let view = LexiconView::new(&bytes).unwrap();
+/// let Some(Entry::Noun(noun)) = view.get("боец").next() else { panic!() };
+/// assert_eq!(noun.inflect(CaseEx::Dative, Number::Singular).as_str(), "бойцу");
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub struct LexiconView<'a> {
+    data: &'a [u8],
+    len: usize,
+}
+
+/// A record in the binary lexicon, referencing the data.
+struct Record<'a> {
+    lemma: &'a str,
+    tag: u8,
+    info: [u8; INFO_LEN],
+    stem_len: usize,
+    stress_at: usize,
+    letters: &'a [u8],
+}
+
+impl Lexicon {
+    /// Serializes the lexicon into the compact binary format, readable with [`LexiconView`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeLexiconError`] if a lemma is longer than 255 bytes, or a word is longer
+    /// than 255 letters.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeLexiconError> {
+        let mut order: Vec<usize> = (0..self.entries.len()).collect();
+        order.sort_by(|&a, &b| self.entries[a].0.cmp(&self.entries[b].0));
+
+        let records_start = HEADER_LEN + order.len() * 4;
+        let mut offsets = Vec::with_capacity(records_start);
+        let mut records = Vec::new();
+
+        offsets.extend_from_slice(&MAGIC);
+        offsets.extend_from_slice(&(order.len() as u32).to_le_bytes());
+
+        for i in order {
+            let (lemma, entry) = &self.entries[i];
+            offsets.extend_from_slice(&((records_start + records.len()) as u32).to_le_bytes());
+
+            let (tag, info, stem) = match entry {
+                Entry::Noun(noun) => (TAG_NOUN, pack_noun_info(noun.info()), noun.stem()),
+                Entry::Adjective(adj) => {
+                    let tag =
+                        if adj.is_reflexive() { TAG_ADJECTIVE_REFLEXIVE } else { TAG_ADJECTIVE };
+                    (tag, pack_adjective_info(adj.info()), adj.stem())
+                },
+                Entry::Pronoun(pro) => (TAG_PRONOUN, pack_pronoun_info(pro.info()), pro.stem()),
+            };
+            let letters = stem.as_letters();
+
+            let word_len =
+                |len: usize| u8::try_from(len).or(Err(EncodeLexiconError::WordTooLong(i)));
+
+            records.push(u8::try_from(lemma.len()).or(Err(EncodeLexiconError::LemmaTooLong(i)))?);
+            records.extend_from_slice(lemma.as_bytes());
+            records.push(tag);
+            records.extend_from_slice(&info);
+            records.push(word_len(stem.stem_letters().len())?);
+            records.push(word_len(stem.stress_at())?);
+            records.push(word_len(letters.len())?);
+            records.extend(letters.iter().map(|x| x.to_byte() as u8));
+        }
+
+        offsets.extend_from_slice(&records);
+        Ok(offsets)
+    }
+}
+
+impl<'a> LexiconView<'a> {
+    /// Constructs a view of the binary lexicon data, validating the layout of all of its records.
+    ///
+    /// Only the structure and the packed bytes of the records are checked here, without
+    /// allocating anything. The entries themselves are decoded lazily, in [`get`][Self::get]
+    /// and [`iter`][Self::iter].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LexiconDataError`] if the data is truncated or malformed.
+    pub fn new(data: &'a [u8]) -> Result<Self, LexiconDataError> {
+        let (Some(magic), Some(len)) = (data.first_chunk::<4>(), read_u32(data, 4)) else {
+            return Err(LexiconDataError::InvalidHeader);
+        };
+        if *magic != MAGIC {
+            return Err(LexiconDataError::InvalidHeader);
+        }
+        if data.len() < HEADER_LEN + len * 4 {
+            return Err(LexiconDataError::UnexpectedEnd);
+        }
+
+        let view = Self { data, len };
+        let mut prev_lemma = "";
+
+        for i in 0..len {
+            let offset = view.offset(i);
+            let record =
+                read_record(data, offset).ok_or(LexiconDataError::InvalidRecord(offset))?;
+
+            if record.lemma < prev_lemma || !record.is_valid() {
+                return Err(LexiconDataError::InvalidRecord(offset));
+            }
+            prev_lemma = record.lemma;
+        }
+        Ok(view)
+    }
+
+    /// Returns the number of entries in the lexicon.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns `true` if the lexicon has no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns all entries with the specified lemma, decoding only the matching records.
+    /// The lemma must not contain stress marks.
+    pub fn get(&self, lemma: &str) -> impl Iterator<Item = Entry> + use<'a> {
+        let start = self.partition_point(|x| x < lemma);
+        let end = start + self.partition_point_from(start, |x| x == lemma);
+        let view = *self;
+        (start..end).filter_map(move |i| view.record(i).to_entry())
+    }
+
+    /// Returns an iterator over all lemmas and entries in the lexicon, sorted by lemma.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, Entry)> + use<'a> {
+        let view = *self;
+        (0..self.len).filter_map(move |i| {
+            let record = view.record(i);
+            Some((record.lemma, record.to_entry()?))
+        })
+    }
+
+    fn offset(&self, index: usize) -> usize {
+        read_u32(self.data, HEADER_LEN + index * 4).unwrap_or(usize::MAX)
+    }
+    fn record(&self, index: usize) -> Record<'a> {
+        // All records are validated in the constructor
+        read_record(self.data, self.offset(index)).expect("record should be valid")
+    }
+    fn partition_point(&self, pred: impl Fn(&str) -> bool) -> usize {
+        self.partition_point_from(0, pred)
+    }
+    fn partition_point_from(&self, start: usize, pred: impl Fn(&str) -> bool) -> usize {
+        let (mut lo, mut hi) = (start, self.len);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(self.record(mid).lemma) { lo = mid + 1 } else { hi = mid }
+        }
+        lo - start
+    }
+}
+
+impl Record<'_> {
+    fn is_valid(&self) -> bool {
+        let info_valid = match self.tag {
+            TAG_NOUN => unpack_noun_info(self.info).is_some(),
+            TAG_ADJECTIVE | TAG_ADJECTIVE_REFLEXIVE => unpack_adjective_info(self.info).is_some(),
+            TAG_PRONOUN => unpack_pronoun_info(self.info).is_some(),
+            _ => false,
+        };
+        info_valid
+            && self.stem_len <= self.letters.len()
+            && self.stress_at <= self.letters.len()
+            && self.letters.iter().all(|&x| byte_letter(x).is_some())
+    }
+
+    fn to_entry(&self) -> Option<Entry> {
+        let letters = (self.letters.iter())
+            .map(|&x| Some(byte_letter(x)?.to_utf8()))
+            .collect::<Option<Vec<Utf8Letter>>>()?;
+
+        if self.stem_len > letters.len() || self.stress_at > letters.len() {
+            return None;
+        }
+        let stem = Word::new(&letters, self.stem_len, self.stress_at).to_owned();
+
+        Some(match self.tag {
+            TAG_NOUN => Entry::Noun(Noun::from_stem(stem, unpack_noun_info(self.info)?)),
+            TAG_ADJECTIVE => {
+                Entry::Adjective(Adjective::from_stem(stem, unpack_adjective_info(self.info)?))
+            },
+            TAG_ADJECTIVE_REFLEXIVE => Entry::Adjective(Adjective::from_stem_reflexive(
+                stem,
+                unpack_adjective_info(self.info)?,
+            )),
+            TAG_PRONOUN => {
+                Entry::Pronoun(Pronoun::from_stem(stem, unpack_pronoun_info(self.info)?))
+            },
+            _ => return None,
+        })
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes = data.get(offset..)?.first_chunk::<4>()?;
+    Some(u32::from_le_bytes(*bytes) as usize)
+}
+
+fn read_record(data: &[u8], offset: usize) -> Option<Record<'_>> {
+    let mut rest = data.get(offset..)?;
+    let mut take = |n: usize| {
+        let (left, right) = rest.split_at_checked(n)?;
+        rest = right;
+        Some(left)
+    };
+
+    let lemma_len = take(1)?[0] as usize;
+    let lemma = str::from_utf8(take(lemma_len)?).ok()?;
+    let tag = take(1)?[0];
+    let info = *take(INFO_LEN)?.first_chunk::<INFO_LEN>()?;
+    let &[stem_len, stress_at, letters_len] = take(3)? else { return None };
+    let letters = take(letters_len as usize)?;
+
+    Some(Record {
+        lemma,
+        tag,
+        info,
+        stem_len: stem_len as usize,
+        stress_at: stress_at as usize,
+        letters,
+    })
+}
+
+fn byte_letter(byte: u8) -> Option<ByteLetter> {
+    ByteLetter::from_utf8([if byte >= 0xB0 { 0xD0 } else { 0xD1 }, byte])
+}
+
+const ANY_STRESSES: [AnyStress; 14] = {
+    use AnyStress::*;
+    [A, B, C, D, E, F, Ap, Bp, Cp, Dp, Ep, Fp, Cpp, Fpp]
+};
+
+fn any_stress(bits: u8) -> Option<AnyStress> {
+    ANY_STRESSES.get((bits as usize).checked_sub(1)?).copied()
+}
+
+// Packed declension: [kind << 4 | stem type, flags, stress]
+// Adjective stresses are packed as [full << 4 | short].
+fn pack_declension(decl: Option<Declension>) -> [u8; 3] {
+    match decl {
+        None => [0; 3],
+        Some(Declension::Noun(decl)) => [
+            0x10 | decl.stem_type.to_digit(),
+            decl.flags.bits(),
+            AnyStress::from(decl.stress) as u8,
+        ],
+        Some(Declension::Pronoun(decl)) => [
+            0x20 | decl.stem_type.to_digit(),
+            decl.flags.bits(),
+            AnyStress::from(decl.stress) as u8,
+        ],
+        Some(Declension::Adjective(decl)) => [
+            0x30 | decl.stem_type.to_digit(),
+            decl.flags.bits(),
+            (AnyStress::from(decl.stress.full) as u8) << 4
+                | AnyStress::from(decl.stress.short) as u8,
+        ],
+    }
+}
+
+fn unpack_declension([kind, flags, stress]: [u8; 3]) -> Option<Option<Declension>> {
+    let digit = kind & 0x0F;
+    let flags = DeclensionFlags::from_bits(flags)?;
+
+    Some(Some(match kind >> 4 {
+        0 => return (digit == 0).then_some(None),
+        1 => Declension::Noun(NounDeclension {
+            stem_type: NounStemType::from_digit(digit)?,
+            stress: any_stress(stress)?.try_into().ok()?,
+            flags,
+        }),
+        2 => Declension::Pronoun(PronounDeclension {
+            stem_type: PronounStemType::from_digit(digit)?,
+            stress: any_stress(stress)?.try_into().ok()?,
+            flags,
+        }),
+        3 => Declension::Adjective(AdjectiveDeclension {
+            stem_type: AdjectiveStemType::from_digit(digit)?,
+            stress: AdjectiveStress {
+                full: any_stress(stress >> 4)?.try_into().ok()?,
+                short: any_stress(stress & 0x0F)?.try_into().ok()?,
+            },
+            flags,
+        }),
+        _ => return None,
+    }))
+}
+
+// Packed noun info:
+// [declension × 3, declension gender | gender << 2 | animacy << 4 | tantum << 5, flags]
+fn pack_noun_info(info: NounInfo) -> [u8; INFO_LEN] {
+    let [a, b, c] = pack_declension(info.declension);
+    let tantum = match info.tantum {
+        None => 0,
+        Some(Number::Singular) => 1,
+        Some(Number::Plural) => 2,
+    };
+    let d = info.declension_gender as u8
+        | (info.gender as u8) << 2
+        | (info.animacy as u8) << 4
+        | tantum << 5;
+    [a, b, c, d, info.flags.bits()]
+}
+fn unpack_noun_info([a, b, c, d, e]: [u8; INFO_LEN]) -> Option<NounInfo> {
+    // Nouns don't decline by pronoun declension
+    let declension = unpack_declension([a, b, c])?;
+    if declension.is_some_and(Declension::is_pronoun) {
+        return None;
+    }
+    Some(NounInfo {
+        declension,
+        declension_gender: match d & 0b11 {
+            0 => Gender::Masculine,
+            1 => Gender::Neuter,
+            2 => Gender::Feminine,
+            _ => return None,
+        },
+        gender: match (d >> 2) & 0b11 {
+            0 => GenderEx::Masculine,
+            1 => GenderEx::Neuter,
+            2 => GenderEx::Feminine,
+            _ => GenderEx::Common,
+        },
+        animacy: match (d >> 4) & 0b1 {
+            0 => Animacy::Inanimate,
+            _ => Animacy::Animate,
+        },
+        tantum: match d >> 5 {
+            0 => None,
+            1 => Some(Number::Singular),
+            2 => Some(Number::Plural),
+            _ => return None,
+        },
+        flags: NounFlags::from_bits(e)?,
+    })
+}
+
+// Packed adjective info: [declension × 3, flags | kind << 3, 0]
+fn pack_adjective_info(info: AdjectiveInfo) -> [u8; INFO_LEN] {
+    let [a, b, c] = pack_declension(info.declension);
+    [a, b, c, info.flags.bits() | (info.kind as u8) << 3, 0]
+}
+fn unpack_adjective_info([a, b, c, d, e]: [u8; INFO_LEN]) -> Option<AdjectiveInfo> {
+    // Adjectives don't decline by noun declension
+    let declension = unpack_declension([a, b, c])?;
+    if e != 0 || declension.is_some_and(Declension::is_noun) {
+        return None;
+    }
+    Some(AdjectiveInfo {
+        declension,
+        flags: AdjectiveFlags::from_bits(d & 0b111)?,
+        kind: match d >> 3 {
+            0 => AdjectiveKind::Regular,
+            1 => AdjectiveKind::Pronoun,
+            2 => AdjectiveKind::Numeral,
+            _ => return None,
+        },
+    })
+}
+
+// Packed pronoun info: [declension × 3, 0, 0]
+fn pack_pronoun_info(info: PronounInfo) -> [u8; INFO_LEN] {
+    let [a, b, c] = pack_declension(info.declension);
+    [a, b, c, 0, 0]
+}
+fn unpack_pronoun_info([a, b, c, d, e]: [u8; INFO_LEN]) -> Option<PronounInfo> {
+    // Pronouns don't decline by noun declension
+    let declension = unpack_declension([a, b, c])?;
+    if d != 0 || e != 0 || declension.is_some_and(Declension::is_noun) {
+        return None;
+    }
+    Some(PronounInfo { declension })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let text = "\
+сло́во с 1a
+бое́ц мо 5*b
+но́жницы мн. <ж 5a>
+столо́вая ж <п 1a>
+ко́фе м 0
+кра́сный п 1*a/c′
+деревя́нный п 1a⌧~
+уча́щийся п 4a
+э́тот мс 1a
+сло́во м 1a
+лес м 1c, Р2, П2 (в)
+";
+        let (lexicon, errors) = Lexicon::parse(text);
+        assert_eq!(errors, []);
+        let bytes = lexicon.to_bytes().unwrap();
+        let view = LexiconView::new(&bytes).unwrap();
+        assert_eq!(view.len(), lexicon.len());
+
+        // All entries are decoded to the same values
+        for (lemma, entry) in lexicon.iter() {
+            let entries: Vec<_> = view.get(lemma).collect();
+            assert!(entries.contains(entry), "{lemma}");
+        }
+        // Entries with the same lemma keep their original order
+        let entries: Vec<_> = view.get("слово").collect();
+        assert_eq!(entries, lexicon.get("слово").cloned().collect::<Vec<_>>());
+
+        // Entries are sorted by lemma
+        let lemmas: Vec<_> = view.iter().map(|x| x.0).collect();
+        let mut sorted = lemmas.clone();
+        sorted.sort();
+        assert_eq!(lemmas, sorted);
+
+        assert_eq!(view.get("стол").count(), 0);
+        assert_eq!(view.get("").count(), 0);
+        assert_eq!(view.get("яблоко").count(), 0);
+    }
+
+    #[test]
+    fn invalid_data() {
+        let (lexicon, _) = Lexicon::parse("сло́во с 1a\nбое́ц мо 5*b\n");
+        let bytes = lexicon.to_bytes().unwrap();
+
+        assert_eq!(LexiconView::new(&[]), Err(LexiconDataError::InvalidHeader));
+        assert_eq!(LexiconView::new(&bytes[1..]), Err(LexiconDataError::InvalidHeader));
+        assert_eq!(LexiconView::new(&bytes[..12]), Err(LexiconDataError::UnexpectedEnd));
+
+        let truncated = &bytes[..bytes.len() - 1];
+        assert!(matches!(LexiconView::new(truncated), Err(LexiconDataError::InvalidRecord(_))));
+
+        let mut corrupted = bytes.clone();
+        *corrupted.last_mut().unwrap() = 0x00;
+        assert!(matches!(LexiconView::new(&corrupted), Err(LexiconDataError::InvalidRecord(_))));
+
+        let empty = Lexicon::new().to_bytes().unwrap();
+        assert!(LexiconView::new(&empty).unwrap().is_empty());
+    }
+
+    #[test]
+    fn too_long() {
+        let (mut lexicon, _) = Lexicon::parse("сло́во с 1a\n");
+        let entry = lexicon.iter().next().unwrap().1.clone();
+        assert!(lexicon.to_bytes().is_ok());
+
+        lexicon.insert(&"а".repeat(128), entry);
+        assert_eq!(lexicon.to_bytes(), Err(EncodeLexiconError::LemmaTooLong(1)));
+
+        let mut lexicon = Lexicon::new();
+        let word = format!("а́{}", "б".repeat(255));
+        let noun = Noun::from_word(&word, "м 1a".parse().unwrap()).unwrap();
+        lexicon.insert("а", Entry::Noun(noun));
+        assert_eq!(lexicon.to_bytes(), Err(EncodeLexiconError::WordTooLong(0)));
+    }
+
+    #[test]
+    fn incompatible_declension() {
+        // Replaces the packed declension kind and stress of the only record in the lexicon
+        fn corrupt(text: &str, kind: u8, stress: u8) -> Vec<u8> {
+            let (lexicon, _) = Lexicon::parse(text);
+            let mut bytes = lexicon.to_bytes().unwrap();
+            let info_at = HEADER_LEN + 4 + 1 + bytes[HEADER_LEN + 4] as usize + 1;
+            bytes[info_at] = kind;
+            bytes[info_at + 2] = stress;
+            bytes
+        }
+        let valid = |bytes: &[u8]| LexiconView::new(bytes).is_ok();
+
+        // Nouns can have noun and adjective declensions
+        assert!(valid(&corrupt("сло́во с 1a", 0x11, 0x01)));
+        assert!(!valid(&corrupt("сло́во с 1a", 0x21, 0x01)));
+        assert!(valid(&corrupt("сло́во с 1a", 0x31, 0x11)));
+
+        // Adjectives can have adjective and pronoun declensions
+        assert!(!valid(&corrupt("кра́сный п 1a", 0x11, 0x01)));
+        assert!(valid(&corrupt("кра́сный п 1a", 0x21, 0x01)));
+        assert!(valid(&corrupt("кра́сный п 1a", 0x31, 0x11)));
+
+        // Pronouns can have pronoun and adjective declensions
+        assert!(!valid(&corrupt("э́тот мс 1a", 0x11, 0x01)));
+        assert!(valid(&corrupt("э́тот мс 1a", 0x21, 0x01)));
+        assert!(valid(&corrupt("э́тот мс 1a", 0x31, 0x11)));
+    }
+}
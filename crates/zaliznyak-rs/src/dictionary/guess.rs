@@ -0,0 +1,149 @@
+use crate::{
+    dictionary::{Entry, Lexicon},
+    noun::NounInfo,
+    word::Utf8Letter,
+};
+use std::collections::HashMap;
+
+/// Guesses the declension of nouns that aren't in the dictionary, using statistics of word
+/// endings learned from a lexicon.
+///
+/// For every noun learned, the guesser counts the noun's info for each of the word's last 2 to 5
+/// letters. A new word's info is then predicted from the longest ending seen before, choosing the
+/// most frequent info, whose stem type fits the word. If none of the word's endings have been
+/// seen, it falls back to [`NounInfo::guess`].
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::dictionary::{Lexicon, NounGuesser};
+///
+/// let (lexicon, _) = Lexicon::parse("звоно́к м 3*b\nсвисто́к м 3*b\nуро́к м 3a\n");
+/// let guesser = NounGuesser::from_lexicon(&lexicon);
+///
+/// assert_eq!(guesser.guess("гудок").unwrap().to_string(), "м 3*b");
+/// assert_eq!(guesser.guess("порок").unwrap().to_string(), "м 3a");
+/// // Falls back to the built-in heuristics
+/// assert_eq!(guesser.guess("смартфон").unwrap().to_string(), "м 1a");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NounGuesser {
+    endings: HashMap<Box<str>, Vec<(NounInfo, usize)>>,
+}
+
+const MIN_ENDING_LEN: usize = 2;
+const MAX_ENDING_LEN: usize = 5;
+
+impl NounGuesser {
+    /// Constructs a new `NounGuesser`, that hasn't learned any words yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Constructs a `NounGuesser`, and learns all of the nouns in the lexicon.
+    #[must_use]
+    pub fn from_lexicon(lexicon: &Lexicon) -> Self {
+        let mut guesser = Self::new();
+        for (lemma, entry) in lexicon.iter() {
+            if let Entry::Noun(noun) = entry {
+                guesser.learn(lemma, noun.info());
+            }
+        }
+        guesser
+    }
+
+    /// Learns the endings of a noun with the specified info. The lemma must not contain stress
+    /// marks.
+    pub fn learn(&mut self, lemma: &str, info: NounInfo) {
+        for ending in endings(lemma) {
+            let counts = self.endings.entry(ending.into()).or_default();
+
+            match counts.iter_mut().find(|x| x.0 == info) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((info, 1)),
+            }
+        }
+    }
+
+    /// Guesses the most likely declension of a noun from its nominative singular form. The word
+    /// may contain stress marks, but they're ignored.
+    ///
+    /// Returns `None` if the word isn't a valid lowercase Cyrillic word, or its stem couldn't be
+    /// identified.
+    #[must_use]
+    pub fn guess(&self, word: &str) -> Option<NounInfo> {
+        let word: String = word.chars().filter(|&ch| ch != '\u{301}').collect();
+        let letters: Vec<Utf8Letter> =
+            word.chars().map(Utf8Letter::from_char).collect::<Option<_>>()?;
+
+        for ending in endings(&word).rev() {
+            let Some(counts) = self.endings.get(ending) else { continue };
+
+            // Ties are resolved in favor of the info learned first
+            let mut best: Option<(NounInfo, usize)> = None;
+            for &(info, count) in counts {
+                let fits = match info.declension {
+                    Some(decl) => decl.identify(&letters).is_some_and(|x| x.1),
+                    None => true,
+                };
+                if fits && best.is_none_or(|x| count > x.1) {
+                    best = Some((info, count));
+                }
+            }
+            if let Some((info, _)) = best {
+                return Some(info);
+            }
+        }
+        NounInfo::guess(&word)
+    }
+}
+
+/// Returns the word's endings, from the shortest to the longest, that are used in statistics.
+fn endings(word: &str) -> impl DoubleEndedIterator<Item = &str> {
+    let starts: Vec<usize> = word.char_indices().map(|x| x.0).collect();
+    let len = starts.len();
+
+    (MIN_ENDING_LEN..=MAX_ENDING_LEN.min(len)).map(move |n| &word[starts[len - n]..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guess() {
+        let text = "\
+звоно́к м 3*b
+свисто́к м 3*b
+уро́к м 3a
+кни́жка ж 3*a
+ло́жка ж 3*a
+река́ ж 3d
+ко́шка жо 3*a
+";
+        let (lexicon, errors) = Lexicon::parse(text);
+        assert_eq!(errors, []);
+        let guesser = NounGuesser::from_lexicon(&lexicon);
+        let guess = |word| guesser.guess(word).unwrap().to_string();
+
+        // The most frequent info for the ending -ок
+        assert_eq!(guess("гудо́к"), "м 3*b");
+        // The longest learned ending takes precedence: -рок
+        assert_eq!(guess("порок"), "м 3a");
+        // The learned ending -шка takes precedence over -ка
+        assert_eq!(guess("мушка"), "жо 3*a");
+        assert_eq!(guess("флешка"), "жо 3*a");
+        assert_eq!(guess("сумка"), "ж 3*a");
+        assert_eq!(guess("щека"), "ж 3d");
+
+        // Learned infos, whose stem type doesn't fit the word, are skipped
+        let mut guesser = NounGuesser::new();
+        guesser.learn("столовая", "ж <п 1a>".parse().unwrap());
+        guesser.learn("рабочая", "мо <п 4a>".parse().unwrap());
+        assert_eq!(guesser.guess("душевая").unwrap().to_string(), "ж <п 1a>");
+        assert_eq!(guesser.guess("прихожая").unwrap().to_string(), "мо <п 4a>");
+
+        assert_eq!(guesser.guess("смартфон").unwrap().to_string(), "м 1a");
+        assert_eq!(guesser.guess("smartphone"), None);
+    }
+}
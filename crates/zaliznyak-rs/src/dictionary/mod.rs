@@ -0,0 +1,298 @@
+//! Loading of dictionary entries in Zaliznyak's text format.
+//!
+//! Each line of the dictionary contains a stressed word followed by its grammatical info,
+//! separated by a space: `сло́во с 1a`, `бое́ц мо 5*b`, `кра́сный п 1*a/c′`, `э́тот мс 1a`.
+//! The info is parsed as [`NounInfo`], [`AdjectiveInfo`] or [`PronounInfo`], depending on its
+//! first word.
+//! Unstressed words (`слово с 1a`) are accepted too, with the stress placed on the first vowel.
+//! The position of stress in the stem doesn't affect the letters of the word's forms, only their
+//! stress marks.
+//! Empty lines and lines starting with `#` are skipped.
+//!
+//! # Examples
+//!
+//! ```
+//! use zaliznyak::{
+//!     categories::{CaseEx, Number},
+//!     dictionary::{Entry, Lexicon, ParseEntryError},
+//! };
+//!
+//! let (lexicon, errors) = Lexicon::parse("сло́во с 1a\nбое́ц мо 5*b\nзверь мо 2z\n");
+//!
+//! let Some(Entry::Noun(noun)) = lexicon.get("боец").next() else { panic!() };
+//! assert_eq!(noun.inflect(CaseEx::Genitive, Number::Plural).as_str(), "бойцов");
+//!
+//! // Invalid lines are skipped, and errors are collected with their line numbers
+//! assert_eq!(lexicon.len(), 2);
+//! assert_eq!(errors.len(), 1);
+//! assert_eq!(errors[0].line, 3);
+//! assert!(matches!(errors[0].error, ParseEntryError::InvalidNounInfo(_)));
+//! ```
+use crate::{
+    adjective::{Adjective, AdjectiveInfo, NewAdjectiveError, ParseAdjectiveInfoError},
+    noun::{NewNounError, Noun, NounInfo, ParseNounInfoError},
+    pronoun::{NewPronounError, ParsePronounInfoError, Pronoun, PronounInfo},
+    word::{ParseWordError, WordBuf, with_default_stress},
+};
+use std::collections::HashMap;
+use thiserror::Error;
+
+mod binary;
+mod guess;
+
+pub use binary::*;
+pub use guess::*;
+
+/// A dictionary entry: a noun, an adjective or a pronoun.
+#[derive(Debug, Clone, Eq, Hash)]
+#[derive_const(PartialEq)]
+pub enum Entry {
+    /// A noun entry, with info like `мо 5*b` or `ж <п 1a>`.
+    Noun(Noun),
+    /// An adjective entry, with info like `п 1*a/c′` or `мс-п 6b`.
+    Adjective(Adjective),
+    /// A pronoun entry, with info like `мс 1a`.
+    Pronoun(Pronoun),
+}
+
+/// A collection of dictionary entries, that can be looked up by their lemmas.
+#[derive(Debug, Clone, Default)]
+pub struct Lexicon {
+    entries: Vec<(Box<str>, Entry)>,
+    lemmas: HashMap<Box<str>, Vec<usize>>,
+}
+
+/// Error type for parsing dictionary entries.
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum ParseEntryError {
+    /// The entry's word isn't a valid lowercase Cyrillic word.
+    #[error("error parsing word: {0}")]
+    InvalidWord(ParseWordError),
+    /// The line doesn't contain a space separating the word from its info.
+    #[error("missing word info")]
+    MissingInfo,
+    /// The entry's info couldn't be parsed as [`NounInfo`].
+    #[error("error parsing noun info: {0}")]
+    InvalidNounInfo(ParseNounInfoError),
+    /// The entry's info couldn't be parsed as [`AdjectiveInfo`].
+    #[error("error parsing adjective info: {0}")]
+    InvalidAdjectiveInfo(ParseAdjectiveInfoError),
+    /// The entry's info couldn't be parsed as [`PronounInfo`].
+    #[error("error parsing pronoun info: {0}")]
+    InvalidPronounInfo(ParsePronounInfoError),
+    /// The noun couldn't be constructed from the word (see [`Noun::from_word`]).
+    #[error("invalid noun: {0}")]
+    InvalidNoun(NewNounError),
+    /// The adjective couldn't be constructed from the word (see [`Adjective::from_word`]).
+    #[error("invalid adjective: {0}")]
+    InvalidAdjective(NewAdjectiveError),
+    /// The pronoun couldn't be constructed from the word (see [`Pronoun::from_word`]).
+    #[error("invalid pronoun: {0}")]
+    InvalidPronoun(NewPronounError),
+}
+
+/// An error that occurred while parsing a specific line of the dictionary.
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+#[error("line {line}: {error}")]
+pub struct LineError {
+    /// The 1-based number of the line.
+    pub line: usize,
+    /// The error that occurred while parsing the line.
+    pub error: ParseEntryError,
+}
+
+impl Lexicon {
+    /// Constructs a new, empty `Lexicon`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses dictionary lines into a lexicon. Lines that couldn't be parsed are skipped,
+    /// and their errors are returned along with the lexicon.
+    pub fn parse(text: &str) -> (Self, Vec<LineError>) {
+        let mut lexicon = Self::new();
+        let mut errors = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match parse_entry(line) {
+                Ok((lemma, entry)) => lexicon.insert(&lemma, entry),
+                Err(error) => errors.push(LineError { line: index + 1, error }),
+            };
+        }
+        (lexicon, errors)
+    }
+
+    /// Adds an entry to the lexicon.
+    pub fn insert(&mut self, lemma: &str, entry: Entry) {
+        self.lemmas.entry(lemma.into()).or_default().push(self.entries.len());
+        self.entries.push((lemma.into(), entry));
+    }
+
+    /// Returns all entries with the specified lemma. The lemma must not contain stress marks.
+    pub fn get(&self, lemma: &str) -> impl Iterator<Item = &Entry> {
+        let indices = self.lemmas.get(lemma).map_or(&[][..], Vec::as_slice);
+        indices.iter().map(|&i| &self.entries[i].1)
+    }
+
+    /// Returns all entries with the specified lemma, along with their indices in insertion order
+    /// and the lemma, borrowed from the lexicon.
+    pub(crate) fn get_indexed(&self, lemma: &str) -> impl Iterator<Item = (usize, &str, &Entry)> {
+        let indices = self.lemmas.get(lemma).map_or(&[][..], Vec::as_slice);
+        indices.iter().map(|&i| (i, &*self.entries[i].0, &self.entries[i].1))
+    }
+
+    /// Returns an iterator over all lemmas and entries in the lexicon, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Entry)> {
+        self.entries.iter().map(|(lemma, entry)| (&**lemma, entry))
+    }
+
+    /// Returns the number of entries in the lexicon.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    /// Returns `true` if the lexicon has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+fn parse_entry(line: &str) -> Result<(Box<str>, Entry), ParseEntryError> {
+    let (mut word, info) = line.split_once(' ').ok_or(ParseEntryError::MissingInfo)?;
+
+    // Parse the word separately first, to report the specific parsing error
+    let stressed;
+    let lemma: WordBuf = match word.parse() {
+        Err(ParseWordError::NoStress) => {
+            stressed = with_default_stress(word);
+            word = &stressed;
+            word.parse()
+        },
+        result => result,
+    }
+    .map_err(ParseEntryError::InvalidWord)?;
+
+    let entry = if ["п ", "мс-п ", "числ.-п "].iter().any(|x| info.starts_with(x)) {
+        // Adjectives: 'п 1*a/c′✕', 'мс-п 1a', 'числ.-п <п 1a>'
+        let info: AdjectiveInfo = info.parse().map_err(ParseEntryError::InvalidAdjectiveInfo)?;
+
+        Entry::Adjective(
+            Adjective::from_word(word, info).map_err(ParseEntryError::InvalidAdjective)?,
+        )
+    } else if info.starts_with("мс ") {
+        // Pronouns: 'мс 1a'
+        let info: PronounInfo = info.parse().map_err(ParseEntryError::InvalidPronounInfo)?;
+
+        Entry::Pronoun(Pronoun::from_word(word, info).map_err(ParseEntryError::InvalidPronoun)?)
+    } else {
+        // Nouns: 'мо 5*b', 'ж <п 1a>', 'мн. <ж 5a>'
+        let info: NounInfo = info.parse().map_err(ParseEntryError::InvalidNounInfo)?;
+
+        Entry::Noun(Noun::from_word(word, info).map_err(ParseEntryError::InvalidNoun)?)
+    };
+
+    Ok((lemma.as_str().into(), entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::{Animacy, Case, CaseEx, DeclInfo, Gender, Number};
+
+    #[test]
+    fn parse() {
+        let text = "\
+# Nouns
+сло́во с 1a
+бое́ц мо 5*b
+столо́вая ж <п 1a>
+
+# Adjectives and pronouns
+кра́сный п 1*a/c′
+мо́й мс-п 6b
+э́тот мс 1a
+";
+        let (lexicon, errors) = Lexicon::parse(text);
+        assert_eq!(errors, []);
+        assert_eq!(lexicon.len(), 6);
+
+        let Some(Entry::Noun(noun)) = lexicon.get("слово").next() else { panic!() };
+        assert_eq!(noun.inflect(CaseEx::Dative, Number::Plural).as_str(), "словам");
+        let Some(Entry::Noun(noun)) = lexicon.get("боец").next() else { panic!() };
+        assert_eq!(noun.inflect(CaseEx::Instrumental, Number::Singular).as_str(), "бойцом");
+        let Some(Entry::Noun(noun)) = lexicon.get("столовая").next() else { panic!() };
+        assert_eq!(noun.inflect(CaseEx::Genitive, Number::Singular).as_str(), "столовой");
+
+        let Some(Entry::Adjective(adj)) = lexicon.get("красный").next() else { panic!() };
+        assert_eq!(
+            adj.inflect(DeclInfo {
+                case: Case::Genitive,
+                number: Number::Singular,
+                gender: Gender::Feminine,
+                animacy: Animacy::Inanimate,
+            })
+            .as_str(),
+            "красной"
+        );
+        let Some(Entry::Adjective(adj)) = lexicon.get("мой").next() else { panic!() };
+        assert_eq!(adj.info().to_string(), "мс-п 6b");
+        let Some(Entry::Pronoun(pro)) = lexicon.get("этот").next() else { panic!() };
+        assert_eq!(pro.info().to_string(), "мс 1a");
+
+        let lemmas: Vec<_> = lexicon.iter().map(|x| x.0).collect();
+        assert_eq!(lemmas, ["слово", "боец", "столовая", "красный", "мой", "этот"]);
+        assert_eq!(lexicon.get("стол").count(), 0);
+    }
+
+    #[test]
+    fn unstressed() {
+        let (lexicon, errors) = Lexicon::parse("слово с 1a\nбоец мо 5*b\nкрасный п 1*a/c′\n");
+        assert_eq!(errors, []);
+
+        let Some(Entry::Noun(noun)) = lexicon.get("слово").next() else { panic!() };
+        assert_eq!(noun.inflect(CaseEx::Dative, Number::Plural).as_str(), "словам");
+        let Some(Entry::Noun(noun)) = lexicon.get("боец").next() else { panic!() };
+        assert_eq!(noun.inflect(CaseEx::Genitive, Number::Plural).as_str(), "бойцов");
+        let Some(Entry::Adjective(adj)) = lexicon.get("красный").next() else { panic!() };
+        assert_eq!(adj.info().to_string(), "п 1*a/c′");
+    }
+
+    #[test]
+    fn errors() {
+        let text = "\
+сло́во с 1a
+слово с 1a
+сло́во
+сло́во с 1z
+кра́сный п 9a
+э́тот мс 1x
+word м 1a
+бое́ц мо 1*b
+сло́во м 1a
+сло́во с 1a
+вкл м 1a
+";
+        let (lexicon, errors) = Lexicon::parse(text);
+        assert_eq!(lexicon.len(), 4);
+        assert_eq!(lexicon.get("слово").count(), 4);
+
+        let errors: Vec<_> = errors.iter().map(|x| (x.line, x.error)).collect();
+        assert_eq!(errors.len(), 7);
+        assert_eq!(errors[0], (3, ParseEntryError::MissingInfo));
+        assert!(matches!(errors[1], (4, ParseEntryError::InvalidNounInfo(_))));
+        assert!(matches!(errors[2], (5, ParseEntryError::InvalidAdjectiveInfo(_))));
+        assert!(matches!(errors[3], (6, ParseEntryError::InvalidPronounInfo(_))));
+        assert_eq!(errors[4], (7, ParseEntryError::InvalidWord(ParseWordError::NonCyrillic)));
+        assert_eq!(errors[5], (8, ParseEntryError::InvalidNoun(NewNounError::NotMatchingStemType)));
+        // Words without vowels can't be stressed at all
+        assert_eq!(errors[6], (11, ParseEntryError::InvalidWord(ParseWordError::NoStress)));
+    }
+}
@@ -1,6 +1,5 @@
 #![feature(const_trait_impl)]
 #![feature(derive_const)]
-#![feature(core_intrinsics)]
 #![feature(const_cmp)]
 #![feature(const_ops)]
 #![feature(const_try)]
@@ -11,25 +10,31 @@
 #![feature(const_destruct)]
 #![feature(const_option_ops)]
 #![feature(const_result_trait_fn)]
-#![feature(const_eval_select)]
 #![feature(box_vec_non_null)]
-#![feature(str_from_raw_parts)]
 #![feature(ptr_cast_array)]
 #![feature(cast_maybe_uninit)]
 #![feature(slice_from_ptr_range)]
 #![feature(const_slice_from_ptr_range)]
 #![feature(maybe_uninit_uninit_array_transpose)]
-#![feature(substr_range)]
 #![cfg_attr(test, feature(test))]
 #![allow(internal_features, confusable_idents)]
 #![allow(clippy::deref_addrof)]
 
 pub mod adjective;
+pub mod analyzer;
 pub mod categories;
+pub mod conjugation;
 pub mod declension;
+pub mod dictionary;
+pub mod names;
 pub mod noun;
+pub mod numeral;
+pub mod paradigm;
+pub mod phrase;
+pub mod preposition;
 pub mod pronoun;
 pub mod stress;
+pub mod verb;
 pub mod word;
 
 mod util;
@@ -0,0 +1,186 @@
+use crate::{
+    categories::Gender,
+    names::{FullName, is_consonant},
+};
+
+/// A person's gender, guessed from their name. See [`guess_gender`].
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub struct GenderGuess {
+    /// The guessed gender, either masculine or feminine.
+    pub gender: Gender,
+    /// How reliable the guess is.
+    pub confidence: Confidence,
+}
+
+/// The reliability of a [`GenderGuess`], from least to most reliable.
+#[derive(Debug, Copy, Eq, Hash, PartialOrd, Ord)]
+#[derive_const(Clone, PartialEq)]
+pub enum Confidence {
+    /// Guessed from the given name's ending alone: **Игорь**, **Анна**.
+    Low,
+    /// The given name is in the built-in list of names, whose ending doesn't match their gender:
+    /// **Никита**, **Илья**, **Любовь**.
+    Medium,
+    /// Determined by the patronymic: **Сергеевич**, **Петровна**.
+    High,
+}
+
+/// Guesses a person's gender from their given name and patronymic.
+///
+/// Patronymics in **-ич** are masculine, and ones in **-вна**, **-чна**, **-инична** are
+/// feminine. Without a recognizable patronymic, the given name is checked against a small list
+/// of exceptions (**Никита**, **Илья**, **Любовь**), and then the gender is guessed from its
+/// ending: names in **-а**/**-я** are feminine, and names ending in consonants are masculine.
+///
+/// Returns `None` if the gender couldn't be guessed, for example, for names that are common to
+/// both genders (**Саша**, **Женя**), or ones ending in other vowels.
+///
+/// # Examples
+///
+/// The guessed gender can be used as the referent of a noun of common gender:
+///
+/// ```
+/// use zaliznyak::{
+///     adjective::Adjective,
+///     categories::{CaseEx, Gender, Number},
+///     names::{Confidence, guess_gender},
+///     noun::Noun,
+///     phrase::NounPhrase,
+/// };
+///
+/// let guess = guess_gender("Никита", None).unwrap();
+/// assert_eq!(guess.gender, Gender::Masculine);
+/// assert_eq!(guess.confidence, Confidence::Medium);
+///
+/// let guess = guess_gender("Женя", Some("Петровна")).unwrap();
+/// assert_eq!(guess.gender, Gender::Feminine);
+/// assert_eq!(guess.confidence, Confidence::High);
+///
+/// let noun = Noun::from_word("у́мница", "мо-жо 5a".parse().unwrap()).unwrap();
+/// let adj = Adjective::from_word("большо́й", "п 4b".parse().unwrap()).unwrap();
+/// let mut phrase = NounPhrase { adjectives: vec![adj], ..NounPhrase::new(noun) };
+///
+/// phrase.referent = guess_gender("Илья", None).map(|x| x.gender);
+/// assert_eq!(phrase.inflect(CaseEx::Dative, Number::Singular), "большому умнице");
+/// ```
+#[must_use]
+pub fn guess_gender(given_name: &str, patronymic: Option<&str>) -> Option<GenderGuess> {
+    let guess = |gender, confidence| Some(GenderGuess { gender, confidence });
+
+    if let Some(patronymic) = patronymic.map(str::to_lowercase) {
+        if patronymic.ends_with("ич") {
+            return guess(Gender::Masculine, Confidence::High);
+        }
+        if patronymic.ends_with("вна") || patronymic.ends_with("чна") {
+            return guess(Gender::Feminine, Confidence::High);
+        }
+    }
+
+    let name = given_name.to_lowercase();
+    if MASCULINE_EXCEPTIONS.contains(&name.as_str()) {
+        return guess(Gender::Masculine, Confidence::Medium);
+    }
+    if FEMININE_EXCEPTIONS.contains(&name.as_str()) {
+        return guess(Gender::Feminine, Confidence::Medium);
+    }
+    if COMMON_NAMES.contains(&name.as_str()) {
+        return None;
+    }
+
+    match name.chars().next_back()? {
+        'а' | 'я' => guess(Gender::Feminine, Confidence::Low),
+        'й' | 'ь' => guess(Gender::Masculine, Confidence::Low),
+        ch if is_consonant(ch) => guess(Gender::Masculine, Confidence::Low),
+        _ => None,
+    }
+}
+
+impl FullName {
+    /// Guesses the person's gender from their given name and patronymic. See [`guess_gender`].
+    #[must_use]
+    pub fn guess_gender(&self) -> Option<GenderGuess> {
+        guess_gender(&self.given_name, self.patronymic.as_deref())
+    }
+}
+
+// Men's names ending in -а/-я
+#[rustfmt::skip]
+const MASCULINE_EXCEPTIONS: [&str; 13] = [
+    "никита", "илья", "фома", "кузьма", "лука", "савва", "данила", "гаврила", "фока", "иона",
+    "мина", "добрыня", "муса",
+];
+// Women's names ending in -ь and in consonants
+#[rustfmt::skip]
+const FEMININE_EXCEPTIONS: [&str; 11] = [
+    "любовь", "нинель", "адель", "ассоль", "эсфирь", "юдифь", "рахиль", "руфь", "кармен", "ирэн",
+    "элизабет",
+];
+// Short names, used by both men and women
+const COMMON_NAMES: [&str; 8] = ["саша", "женя", "валя", "шура", "тоша", "сима", "паша", "слава"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patronymic() {
+        let gender = |name, patronymic| guess_gender(name, Some(patronymic)).unwrap().gender;
+
+        assert_eq!(gender("Пётр", "Сергеевич"), Gender::Masculine);
+        assert_eq!(gender("Владимир", "Ильич"), Gender::Masculine);
+        assert_eq!(gender("Анна", "Петровна"), Gender::Feminine);
+        assert_eq!(gender("Анна", "Ильинична"), Gender::Feminine);
+        assert_eq!(gender("Ольга", "Никитична"), Gender::Feminine);
+        // The patronymic takes precedence over the given name
+        assert_eq!(gender("Саша", "ИГОРЕВИЧ"), Gender::Masculine);
+
+        let guess = guess_gender("Женя", Some("Петровна")).unwrap();
+        assert_eq!(guess.confidence, Confidence::High);
+    }
+
+    #[test]
+    fn exceptions() {
+        let guess = |name| guess_gender(name, None).map(|x| (x.gender, x.confidence));
+
+        // Every exception's gender doesn't match its ending, otherwise it'd be redundant
+        for name in MASCULINE_EXCEPTIONS {
+            assert!(name.ends_with(['а', 'я']), "{name}");
+            assert_eq!(guess(name), Some((Gender::Masculine, Confidence::Medium)), "{name}");
+        }
+        for name in FEMININE_EXCEPTIONS {
+            assert!(!name.ends_with(['а', 'я']), "{name}");
+            assert_eq!(guess(name), Some((Gender::Feminine, Confidence::Medium)), "{name}");
+        }
+        for name in COMMON_NAMES {
+            assert_eq!(guess(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn given_name() {
+        let guess = |name| guess_gender(name, None).map(|x| (x.gender, x.confidence));
+        let (m, f) = (Gender::Masculine, Gender::Feminine);
+
+        assert_eq!(guess("Никита"), Some((m, Confidence::Medium)));
+        assert_eq!(guess("Илья"), Some((m, Confidence::Medium)));
+        assert_eq!(guess("Любовь"), Some((f, Confidence::Medium)));
+        assert_eq!(guess("Кармен"), Some((f, Confidence::Medium)));
+        assert_eq!(guess("Анна"), Some((f, Confidence::Low)));
+        assert_eq!(guess("Мария"), Some((f, Confidence::Low)));
+        assert_eq!(guess("Иван"), Some((m, Confidence::Low)));
+        assert_eq!(guess("Игорь"), Some((m, Confidence::Low)));
+        assert_eq!(guess("Сергей"), Some((m, Confidence::Low)));
+
+        // Names common to both genders, or ending in other vowels
+        assert_eq!(guess("Саша"), None);
+        assert_eq!(guess("Пьеро"), None);
+        assert_eq!(guess("John"), None);
+        // An unrecognized patronymic is ignored
+        assert_eq!(guess_gender("Анна", Some("Оглы")).map(|x| x.gender), Some(f));
+
+        let name: FullName = "Иванова Мария Петровна".parse().unwrap();
+        assert_eq!(name.guess_gender().map(|x| x.gender), Some(f));
+        assert!(Confidence::High > Confidence::Low);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    categories::{Case, Gender, Number},
+    names::{inflect_name, inflect_noun, is_consonant},
+    noun::Noun,
+    stress::NounStress,
+};
+use std::sync::LazyLock;
+
+/// Inflects a given name (имя) into the specified case, for a person of the specified gender.
+///
+/// Names ending in **-а**/**-я** decline as feminine nouns regardless of the person's gender
+/// (**Никите**, **Марии**), names ending in **-ь** decline as masculine or feminine nouns
+/// depending on the person's gender (**Игорю**, **Любови**), and men's names ending in other
+/// consonants decline as masculine nouns. Women's names ending in consonants, and all names
+/// ending in other vowels, don't decline: **Ирэн**, **Пьеро**. The neuter gender is treated as
+/// masculine.
+///
+/// Names that aren't spelled in Cyrillic are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Case, Gender},
+///     names::inflect_given_name,
+/// };
+///
+/// assert_eq!(inflect_given_name("Пётр", Case::Dative, Gender::Masculine), "Петру");
+/// assert_eq!(inflect_given_name("Никита", Case::Dative, Gender::Masculine), "Никите");
+/// assert_eq!(inflect_given_name("Любовь", Case::Instrumental, Gender::Feminine), "Любовью");
+/// assert_eq!(inflect_given_name("Кармен", Case::Dative, Gender::Feminine), "Кармен");
+/// ```
+#[must_use]
+pub fn inflect_given_name(name: &str, case: Case, gender: Gender) -> String {
+    inflect_name(name, case, |word| inflect_lowercase(word, case, gender))
+}
+
+/// Inflects a patronymic (отчество) into the specified case, for a person of the specified
+/// gender. Patronymics decline as nouns: **Сергеевичу**, **Петровной**.
+///
+/// Names that aren't spelled in Cyrillic are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Case, Gender},
+///     names::inflect_patronymic,
+/// };
+///
+/// let case = Case::Instrumental;
+/// assert_eq!(inflect_patronymic("Сергеевич", case, Gender::Masculine), "Сергеевичем");
+/// assert_eq!(inflect_patronymic("Ильич", case, Gender::Masculine), "Ильичом");
+/// assert_eq!(inflect_patronymic("Петровна", Case::Genitive, Gender::Feminine), "Петровны");
+/// ```
+#[must_use]
+pub fn inflect_patronymic(name: &str, case: Case, gender: Gender) -> String {
+    inflect_name(name, case, |word| {
+        // A few short patronymics have the stress on the ending: Ильичом, but Сергеевичем
+        if STRESSED_PATRONYMICS.contains(&word) {
+            let word = inflect_noun(word, Gender::Masculine, NounStress::B, case)?;
+            return Some(word.as_str().to_owned());
+        }
+        inflect_lowercase(word, case, gender)
+    })
+}
+
+fn inflect_lowercase(word: &str, case: Case, gender: Gender) -> Option<String> {
+    // Names with vowel alternations, or with stress on the ending
+    if let Some((_, noun)) = EXCEPTION_NOUNS.iter().find(|x| x.0 == word) {
+        let word = noun.try_inflect(case.into(), Number::Singular).ok()?;
+        return Some(word.as_str().to_owned());
+    }
+
+    let last = word.chars().next_back()?;
+    let gender = match last {
+        'а' | 'я' => Gender::Feminine,
+        'ь' => gender,
+        _ if gender == Gender::Feminine || !is_consonant(last) => return None,
+        _ => Gender::Masculine,
+    };
+    let word = inflect_noun(word, gender, NounStress::A, case)?;
+    Some(word.as_str().to_owned())
+}
+
+#[rustfmt::skip]
+const EXCEPTIONS: [(&str, &str, &str); 5] = [
+    ("пётр", "пётр", "мо 1b, ё"),
+    ("петр", "пётр", "мо 1b, ё"),
+    ("лев", "ле́в", "мо 1*b"),
+    ("павел", "па́вел", "мо 1*a"),
+    ("илья", "илья́", "мо <жо 6b>"),
+];
+static EXCEPTION_NOUNS: LazyLock<Vec<(&str, Noun)>> = LazyLock::new(|| {
+    let noun = |word, info: &str| Noun::from_word(word, info.parse().unwrap()).unwrap();
+    EXCEPTIONS.iter().map(|&(name, stressed, info)| (name, noun(stressed, info))).collect()
+});
+const STRESSED_PATRONYMICS: [&str; 4] = ["ильич", "кузьмич", "лукич", "фомич"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::CaseEx;
+
+    #[test]
+    fn exceptions() {
+        assert_eq!(EXCEPTION_NOUNS.len(), EXCEPTIONS.len());
+        for (name, noun) in EXCEPTION_NOUNS.iter() {
+            let nominative = noun.inflect(CaseEx::Nominative, Number::Singular);
+            assert_eq!(nominative.as_str().replace('ё', "е"), name.replace('ё', "е"));
+        }
+    }
+
+    #[test]
+    fn given_names() {
+        let forms = |name: &str, gender| {
+            Case::VALUES.map(|case| inflect_given_name(name, case, gender)).join(" ")
+        };
+        let (m, f) = (Gender::Masculine, Gender::Feminine);
+
+        assert_eq!(forms("Пётр", m), "Пётр Петра Петру Петра Петром Петре");
+        assert_eq!(forms("Лев", m), "Лев Льва Льву Льва Львом Льве");
+        assert_eq!(forms("Илья", m), "Илья Ильи Илье Илью Ильёй Илье");
+        assert_eq!(forms("Василий", m), "Василий Василия Василию Василия Василием Василии");
+        assert_eq!(forms("Игорь", m), "Игорь Игоря Игорю Игоря Игорем Игоре");
+        assert_eq!(forms("Саша", m), "Саша Саши Саше Сашу Сашей Саше");
+        assert_eq!(forms("Мария", f), "Мария Марии Марии Марию Марией Марии");
+        assert_eq!(forms("Наталья", f), "Наталья Натальи Наталье Наталью Натальей Наталье");
+        assert_eq!(forms("Любовь", f), "Любовь Любови Любови Любовь Любовью Любови");
+
+        // Indeclinable names
+        assert_eq!(forms("Кармен", f), "Кармен Кармен Кармен Кармен Кармен Кармен");
+        assert_eq!(inflect_given_name("Пьеро", Case::Dative, m), "Пьеро");
+        assert_eq!(inflect_given_name("John", Case::Dative, m), "John");
+        // Capitalization is preserved
+        assert_eq!(inflect_given_name("ИВАН", Case::Dative, m), "ИВАНУ");
+        assert_eq!(inflect_given_name("иван", Case::Dative, m), "ивану");
+    }
+
+    #[test]
+    fn patronymics() {
+        let forms = |name: &str, gender| {
+            Case::VALUES.map(|case| inflect_patronymic(name, case, gender)).join(" ")
+        };
+        let (m, f) = (Gender::Masculine, Gender::Feminine);
+
+        assert_eq!(
+            forms("Сергеевич", m),
+            "Сергеевич Сергеевича Сергеевичу Сергеевича Сергеевичем Сергеевиче",
+        );
+        assert_eq!(forms("Ильич", m), "Ильич Ильича Ильичу Ильича Ильичом Ильиче");
+        assert_eq!(forms("Петровна", f), "Петровна Петровны Петровне Петровну Петровной Петровне");
+    }
+}
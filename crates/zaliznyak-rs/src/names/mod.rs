@@ -0,0 +1,211 @@
+//! Declension of Russian personal names: surnames (фамилии), given names (имена) and
+//! patronymics (отчества).
+//!
+//! Names aren't listed in the dictionary, so their declension is determined by their endings and
+//! the gender of the person they refer to. Most names decline as regular nouns (**Пётр** ---
+//! **Петру**, **Анна** --- **Анне**), but there are a few rules specific to names:
+//!
+//! - Surnames in **-ов**, **-ев**, **-ин**, **-ын** have mixed declension, with some endings of
+//!   nouns and some of adjectives: **Иванову**, but **Ивановым**. Their feminine forms decline
+//!   like adjectives, except in the accusative case: **Ивановой**, **Иванову**.
+//! - Surnames in **-ский**, **-цкий**, **-ой**, **-ый** are declined as adjectives: **Толстому**,
+//!   **Достоевской**.
+//! - Surnames in **-ых**, **-их**, **-ко**, and ones ending in other vowels (**Черных**,
+//!   **Шевченко**, **Гюго**) don't decline at all.
+//! - Women's surnames and given names ending in a consonant don't decline: **Анне Шевчук**, but
+//!   **Ивану Шевчуку**.
+//!
+//! # Examples
+//!
+//! ```
+//! use zaliznyak::{
+//!     categories::{Case, Gender},
+//!     names::FullName,
+//! };
+//!
+//! let name: FullName = "Иванов Пётр Сергеевич".parse().unwrap();
+//! assert_eq!(name.inflect(Case::Dative, Gender::Masculine), "Иванову Петру Сергеевичу");
+//!
+//! let name: FullName = "Толстая Анна Ильинична".parse().unwrap();
+//! assert_eq!(name.inflect(Case::Instrumental, Gender::Feminine), "Толстой Анной Ильиничной");
+//! ```
+use crate::{
+    adjective::{Adjective, AdjectiveFlags, AdjectiveInfo, AdjectiveKind},
+    categories::{Animacy, Case, DeclInfo, Gender, Number},
+    declension::{
+        AdjectiveDeclension, AdjectiveStemType, Declension, DeclensionFlags, NounDeclension,
+        NounStemType,
+    },
+    noun::{Noun, NounFlags, NounInfo},
+    stress::{AdjectiveStress, NounStress},
+    word::{Casing, WordBuf, with_default_stress},
+};
+use thiserror::Error;
+
+mod gender;
+mod given_name;
+mod surname;
+
+pub use gender::*;
+pub use given_name::*;
+pub use surname::*;
+
+/// A full Russian name: surname, given name, and optionally a patronymic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FullName {
+    /// The surname (фамилия), in the form used by the person: Иванов or Иванова.
+    pub surname: String,
+    /// The given name (имя).
+    pub given_name: String,
+    /// The patronymic (отчество).
+    pub patronymic: Option<String>,
+}
+
+/// Error type for parsing [`FullName`] from a string.
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum ParseFullNameError {
+    /// The string didn't consist of two or three space-separated words.
+    #[error("expected a surname, a given name and an optional patronymic")]
+    Invalid,
+}
+
+impl FullName {
+    /// Inflects the name into the specified case, for a person of the specified gender. Each part
+    /// is inflected separately, see [`inflect_surname`], [`inflect_given_name`] and
+    /// [`inflect_patronymic`].
+    #[must_use]
+    pub fn inflect(&self, case: Case, gender: Gender) -> String {
+        let mut s = inflect_surname(&self.surname, case, gender);
+        s.push(' ');
+        s.push_str(&inflect_given_name(&self.given_name, case, gender));
+        if let Some(patronymic) = &self.patronymic {
+            s.push(' ');
+            s.push_str(&inflect_patronymic(patronymic, case, gender));
+        }
+        s
+    }
+}
+
+impl std::str::FromStr for FullName {
+    type Err = ParseFullNameError;
+
+    /// Parses a name in the "Фамилия Имя Отчество" order, used in documents.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace().map(str::to_owned);
+
+        let surname = parts.next().ok_or(ParseFullNameError::Invalid)?;
+        let given_name = parts.next().ok_or(ParseFullNameError::Invalid)?;
+        let patronymic = parts.next();
+        if parts.next().is_some() {
+            return Err(ParseFullNameError::Invalid);
+        }
+        Ok(Self { surname, given_name, patronymic })
+    }
+}
+
+impl std::fmt::Display for FullName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.surname, self.given_name)?;
+        if let Some(patronymic) = &self.patronymic {
+            write!(f, " {patronymic}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Inflects a lowercase name as an animate noun, identifying its stem type from its ending.
+/// Returns `None` if the name isn't a valid Cyrillic word, or its stem couldn't be identified.
+fn inflect_noun(word: &str, gender: Gender, stress: NounStress, case: Case) -> Option<WordBuf> {
+    let mut stem: WordBuf = with_default_stress(word).parse().ok()?;
+
+    // Stem type 8 can't be identified from letters (see Declension::identify_trim)
+    let stem_type = match NounStemType::identify_trim(&mut stem)? {
+        NounStemType::Type2 if gender == Gender::Feminine && word.ends_with('ь') => {
+            NounStemType::Type8
+        },
+        stem_type => stem_type,
+    };
+
+    let declension = NounDeclension { stem_type, stress, flags: DeclensionFlags::empty() };
+    let info = NounInfo {
+        declension: Some(Declension::Noun(declension)),
+        declension_gender: gender,
+        gender: gender.into(),
+        animacy: Animacy::Animate,
+        tantum: None,
+        flags: NounFlags::empty(),
+    };
+    Noun::from_stem(stem, info).try_inflect(case.into(), Number::Singular).ok()
+}
+
+/// Inflects a lowercase name in its masculine nominative form as an adjective, identifying its
+/// stem type from its ending. Returns `None` if the name isn't a valid Cyrillic word, or its
+/// stem couldn't be identified.
+fn inflect_adjective(
+    word: &str,
+    stress: AdjectiveStress,
+    case: Case,
+    gender: Gender,
+) -> Option<WordBuf> {
+    let mut stem: WordBuf = with_default_stress(word).parse().ok()?;
+    let (stem_type, _) = AdjectiveStemType::identify_trim(&mut stem)?;
+
+    let declension = AdjectiveDeclension { stem_type, stress, flags: DeclensionFlags::empty() };
+    let info = AdjectiveInfo {
+        declension: Some(Declension::Adjective(declension)),
+        flags: AdjectiveFlags::empty(),
+        kind: AdjectiveKind::Regular,
+    };
+    let decl_info = DeclInfo { case, number: Number::Singular, gender, animacy: Animacy::Animate };
+    Adjective::from_stem(stem, info).try_inflect(decl_info).ok()
+}
+
+const VOWELS: &str = "аеёиоуыэюя";
+
+/// Returns `true` if the character is a lowercase Cyrillic consonant letter, including й.
+fn is_consonant(ch: char) -> bool {
+    ('а'..='я').contains(&ch) && !VOWELS.contains(ch) && ch != 'ъ' && ch != 'ь'
+}
+
+/// Lowercases a name, inflects it with the specified function, and restores its capitalization.
+/// Names in the nominative case, and ones that the function returned `None` for (indeclinable,
+/// or not spelled in Cyrillic), are returned unchanged.
+fn inflect_name(name: &str, case: Case, f: impl FnOnce(&str) -> Option<String>) -> String {
+    if case == Case::Nominative {
+        return name.to_owned();
+    }
+    let (word, casing) = Casing::strip(name);
+    match f(&word) {
+        Some(inflected) => casing.apply(&inflected),
+        None => name.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_name() {
+        let name: FullName = "Иванов Пётр Сергеевич".parse().unwrap();
+        assert_eq!(name.inflect(Case::Genitive, Gender::Masculine), "Иванова Петра Сергеевича");
+        assert_eq!(name.inflect(Case::Dative, Gender::Masculine), "Иванову Петру Сергеевичу");
+        assert_eq!(
+            name.inflect(Case::Instrumental, Gender::Masculine),
+            "Ивановым Петром Сергеевичем"
+        );
+
+        let name: FullName = "Иванова Мария Петровна".parse().unwrap();
+        assert_eq!(name.inflect(Case::Accusative, Gender::Feminine), "Иванову Марию Петровну");
+        assert_eq!(name.inflect(Case::Prepositional, Gender::Feminine), "Ивановой Марии Петровне");
+
+        let name: FullName = "Шевчук Анна".parse().unwrap();
+        assert_eq!(name.patronymic, None);
+        assert_eq!(name.inflect(Case::Dative, Gender::Feminine), "Шевчук Анне");
+        assert_eq!(name.to_string(), "Шевчук Анна");
+
+        assert_eq!("Иванов".parse::<FullName>(), Err(ParseFullNameError::Invalid));
+        assert_eq!("Иванов Пётр Сергеевич Б".parse::<FullName>(), Err(ParseFullNameError::Invalid));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    categories::{Case, Gender},
+    names::{VOWELS, inflect_adjective, inflect_name, inflect_noun, is_consonant},
+    stress::{AdjectiveStress, NounStress},
+};
+
+/// Inflects a surname (фамилия) into the specified case, for a person of the specified gender.
+/// The surname must be in the form used by the person: **Иванов** for men, **Иванова** for
+/// women. The neuter gender is treated as masculine.
+///
+/// - Surnames in **-ов**, **-ев**, **-ин**, **-ын** have mixed declension: **Иванову**, but
+///   **Ивановым**; **Ивановой**, but **Иванову**.
+/// - Surnames in **-ый**, **-ой**, **-ский**, **-цкий**, and their feminine forms in **-ая**,
+///   decline as adjectives: **Толстому**, **Достоевской**.
+/// - Surnames ending in **-а**/**-я** decline as feminine nouns: **Глинке**.
+/// - Men's surnames ending in other consonants decline as masculine nouns: **Шевчуку**, **Гоголю**.
+/// - Women's surnames ending in consonants don't decline: **Анне Шевчук**.
+/// - Surnames in **-ых**, **-их**, and ones ending in other vowels, including **-ко**, don't
+///   decline: **Черных**, **Шевченко**, **Гюго**.
+///
+/// Each part of a double surname is inflected separately: **Римскому-Корсакову**. Names that
+/// aren't spelled in Cyrillic are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Case, Gender},
+///     names::inflect_surname,
+/// };
+///
+/// assert_eq!(inflect_surname("Пушкин", Case::Instrumental, Gender::Masculine), "Пушкиным");
+/// assert_eq!(inflect_surname("Пушкина", Case::Accusative, Gender::Feminine), "Пушкину");
+/// assert_eq!(inflect_surname("Шевчук", Case::Dative, Gender::Masculine), "Шевчуку");
+/// assert_eq!(inflect_surname("Шевчук", Case::Dative, Gender::Feminine), "Шевчук");
+/// ```
+#[must_use]
+pub fn inflect_surname(surname: &str, case: Case, gender: Gender) -> String {
+    if surname.contains('-') {
+        let parts: Vec<_> = surname.split('-').map(|x| inflect_surname(x, case, gender)).collect();
+        return parts.join("-");
+    }
+    inflect_name(surname, case, |word| inflect_lowercase(word, case, gender))
+}
+
+fn inflect_lowercase(word: &str, case: Case, gender: Gender) -> Option<String> {
+    let is_feminine = gender == Gender::Feminine;
+    let gender = if is_feminine { Gender::Feminine } else { Gender::Masculine };
+
+    // Surnames in -ых/-их are fossilized genitive plural forms: Черных, Долгих
+    if word.ends_with("ых") || word.ends_with("их") {
+        return None;
+    }
+
+    // Surnames in -ов/-ин have mixed declension, with noun endings in the nominative, genitive,
+    //   dative and accusative cases, and adjective endings in the others.
+    if !is_feminine && MIXED_SUFFIXES.iter().any(|x| word.ends_with(x)) {
+        return Some(word.to_owned() + MIXED_MASCULINE_ENDINGS[case as usize]);
+    }
+    if is_feminine
+        && let Some(stem) = word.strip_suffix('а')
+        && MIXED_SUFFIXES.iter().any(|x| stem.ends_with(x))
+    {
+        return Some(stem.to_owned() + MIXED_FEMININE_ENDINGS[case as usize]);
+    }
+
+    // Adjectival surnames are inflected from their masculine form
+    if let Some(adjective) = adjective_lemma(word, is_feminine) {
+        let stress =
+            if adjective.ends_with("ой") { AdjectiveStress::B } else { AdjectiveStress::A };
+        let word = inflect_adjective(&adjective, stress, case, gender)?;
+        return Some(word.as_str().to_owned());
+    }
+
+    let last = word.chars().next_back()?;
+    let gender = match last {
+        // Surnames in -иа/-уа are foreign, and don't decline: Гарсиа, Моруа
+        'а' if word.ends_with("иа") || word.ends_with("уа") => return None,
+        'а' | 'я' => Gender::Feminine,
+        _ if is_feminine || !(is_consonant(last) || last == 'ь') => return None,
+        _ => Gender::Masculine,
+    };
+    let word = inflect_noun(word, gender, NounStress::A, case)?;
+    Some(word.as_str().to_owned())
+}
+
+/// Returns the masculine form of an adjectival surname: Толстой, Достоевский. Returns `None` if
+/// the surname doesn't decline as an adjective.
+fn adjective_lemma(word: &str, is_feminine: bool) -> Option<String> {
+    if is_feminine {
+        let (stem, ending) = word.split_at_checked(word.len().checked_sub(4)?)?;
+        let last = stem.chars().next_back()?;
+        return match ending {
+            "ая" if "гкхжшчщ".contains(last) => Some(stem.to_owned() + "ий"),
+            "ая" => Some(stem.to_owned() + "ый"),
+            "яя" => Some(stem.to_owned() + "ий"),
+            _ => None,
+        };
+    }
+
+    let (stem, ending) = word.split_at_checked(word.len().checked_sub(4)?)?;
+    let last = stem.chars().next_back()?;
+    let is_adjective = match ending {
+        "ый" => true,
+        "ий" => "гкхжшчщ".contains(last),
+        // Short surnames in -ой decline as nouns: Цой, Цоя
+        "ой" => stem.chars().any(|ch| VOWELS.contains(ch)),
+        _ => false,
+    };
+    is_adjective.then(|| word.to_owned())
+}
+
+const MIXED_SUFFIXES: [&str; 5] = ["ов", "ев", "ёв", "ин", "ын"];
+const MIXED_MASCULINE_ENDINGS: [&str; 6] = ["", "а", "у", "а", "ым", "е"];
+const MIXED_FEMININE_ENDINGS: [&str; 6] = ["а", "ой", "ой", "у", "ой", "ой"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forms(name: &str, gender: Gender) -> String {
+        Case::VALUES.map(|case| inflect_surname(name, case, gender)).join(" ")
+    }
+
+    #[test]
+    fn mixed() {
+        let (m, f) = (Gender::Masculine, Gender::Feminine);
+
+        assert_eq!(forms("Иванов", m), "Иванов Иванова Иванову Иванова Ивановым Иванове");
+        assert_eq!(forms("Иванова", f), "Иванова Ивановой Ивановой Иванову Ивановой Ивановой");
+        assert_eq!(forms("Королёв", m), "Королёв Королёва Королёву Королёва Королёвым Королёве");
+        assert_eq!(forms("Пушкина", f), "Пушкина Пушкиной Пушкиной Пушкину Пушкиной Пушкиной");
+    }
+
+    #[test]
+    fn adjectival() {
+        let (m, f) = (Gender::Masculine, Gender::Feminine);
+
+        assert_eq!(
+            forms("Достоевский", m),
+            "Достоевский Достоевского Достоевскому Достоевского Достоевским Достоевском",
+        );
+        assert_eq!(
+            forms("Достоевская", f),
+            "Достоевская Достоевской Достоевской Достоевскую Достоевской Достоевской",
+        );
+        assert_eq!(forms("Толстой", m), "Толстой Толстого Толстому Толстого Толстым Толстом");
+        assert_eq!(forms("Толстая", f), "Толстая Толстой Толстой Толстую Толстой Толстой");
+        assert_eq!(forms("Белый", m), "Белый Белого Белому Белого Белым Белом");
+    }
+
+    #[test]
+    fn nouns() {
+        let (m, f) = (Gender::Masculine, Gender::Feminine);
+
+        assert_eq!(forms("Шевчук", m), "Шевчук Шевчука Шевчуку Шевчука Шевчуком Шевчуке");
+        assert_eq!(forms("Гоголь", m), "Гоголь Гоголя Гоголю Гоголя Гоголем Гоголе");
+        assert_eq!(forms("Цой", m), "Цой Цоя Цою Цоя Цоем Цое");
+        assert_eq!(forms("Глинка", m), "Глинка Глинки Глинке Глинку Глинкой Глинке");
+        assert_eq!(forms("Глинка", f), "Глинка Глинки Глинке Глинку Глинкой Глинке");
+
+        // Women's surnames ending in consonants don't decline
+        assert_eq!(forms("Шевчук", f), "Шевчук Шевчук Шевчук Шевчук Шевчук Шевчук");
+        assert_eq!(forms("Гоголь", f), "Гоголь Гоголь Гоголь Гоголь Гоголь Гоголь");
+    }
+
+    #[test]
+    fn indeclinable() {
+        let names = ["Черных", "Долгих", "Шевченко", "Гюго", "Дюбуа", "Smith"];
+
+        for gender in [Gender::Masculine, Gender::Feminine] {
+            for name in names {
+                assert_eq!(inflect_surname(name, Case::Instrumental, gender), name);
+            }
+        }
+    }
+
+    #[test]
+    fn double() {
+        let (m, f) = (Gender::Masculine, Gender::Feminine);
+
+        assert_eq!(inflect_surname("Римский-Корсаков", Case::Dative, m), "Римскому-Корсакову");
+        assert_eq!(inflect_surname("Петрова-Водкина", Case::Genitive, f), "Петровой-Водкиной");
+        assert_eq!(inflect_surname("ЧЕРНЫХ-ИВАНОВ", Case::Dative, m), "ЧЕРНЫХ-ИВАНОВУ");
+    }
+}
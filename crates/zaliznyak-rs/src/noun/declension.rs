@@ -1,6 +1,6 @@
 use crate::{
     categories::{Case, CaseEx, DeclInfo, Gender, IntoNumber, Number},
-    declension::{Declension, NounDeclension, NounStemType},
+    declension::{Declension, InflectError, NounDeclension, NounStemType, unwrap_inflected},
     noun::{Noun, NounInfo},
     stress::{NounStress, StressPos},
     util::InflectionBuf,
@@ -8,37 +8,112 @@ use crate::{
 };
 
 impl Noun {
+    /// Inflects the noun into the specified case and number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the noun's stem doesn't fit its declension. See [`Noun::try_inflect`].
     pub fn inflect(&self, case: CaseEx, number: Number) -> WordBuf {
         self.info.inflect(self.stem.borrow(), case, number)
     }
+    /// Inflects the noun into the specified case and number, returning an error instead of
+    /// panicking if the noun's stem doesn't fit its declension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if the noun's declension couldn't be applied to its stem.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{
+    ///     categories::{CaseEx, Number},
+    ///     declension::InflectError,
+    ///     noun::Noun,
+    /// };
+    ///
+    /// let noun = Noun::from_word("ребёнок", "мо 3°a".parse().unwrap()).unwrap();
+    /// let word = noun.try_inflect(CaseEx::Genitive, Number::Singular).unwrap();
+    /// assert_eq!(word.as_str(), "ребёнка");
+    ///
+    /// // ° alternation isn't defined for stems ending in '-ол'
+    /// let noun = Noun::from_word("стол", "м 1°b".parse().unwrap()).unwrap();
+    /// let result = noun.try_inflect(CaseEx::Genitive, Number::Singular);
+    /// assert_eq!(result, Err(InflectError::InvalidUniqueAlternation));
+    /// ```
+    pub fn try_inflect(&self, case: CaseEx, number: Number) -> Result<WordBuf, InflectError> {
+        self.info.try_inflect(self.stem.borrow(), case, number)
+    }
 }
 
 impl NounInfo {
+    /// Inflects a noun with the specified stem into the specified case and number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stem doesn't fit the declension. See [`NounInfo::try_inflect`].
     pub fn inflect(&self, stem: Word, case: CaseEx, number: Number) -> WordBuf {
+        unwrap_inflected(self.try_inflect(stem, case, number))
+    }
+    /// Inflects a noun with the specified stem into the specified case and number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if the declension couldn't be applied to the stem.
+    pub fn try_inflect(
+        &self,
+        stem: Word,
+        case: CaseEx,
+        number: Number,
+    ) -> Result<WordBuf, InflectError> {
         let mut word = WordBuf::with_stem(stem, 5);
         let mut buf = InflectionBuf::new(&mut word);
 
-        if let Some(decl) = self.declension {
+        if let Some(mut decl) = self.declension {
             let number = self.tantum.unwrap_or(number);
+
+            // Nouns with Р2 and П2 marks have distinct partitive and locative singular forms
+            let case = match case {
+                // Partitive: ending -у/-ю, stressed like dative (ча́ю, са́хару)
+                CaseEx::Partitive if self.flags.has_partitive() && number == Number::Singular => {
+                    CaseEx::Dative
+                },
+                // Locative: ending -у/-ю (-и in feminine), always stressed (в лесу́, в крови́)
+                CaseEx::Locative if self.flags.has_locative() && number == Number::Singular => {
+                    if let Declension::Noun(decl) = &mut decl {
+                        decl.stress = NounStress::B;
+                    }
+                    match self.declension_gender {
+                        Gender::Feminine => CaseEx::Prepositional,
+                        _ => CaseEx::Dative,
+                    }
+                },
+                _ => case,
+            };
             let (case, number) = case.normalize_with(number);
 
             let info =
                 DeclInfo { case, number, gender: self.declension_gender, animacy: self.animacy };
 
             match decl {
-                Declension::Noun(decl) => decl.inflect(info, &mut buf),
-                Declension::Adjective(decl) => decl.inflect(info, &mut buf),
-                Declension::Pronoun(_) => unimplemented!(), // Nouns don't decline by pronoun declension
+                Declension::Noun(decl) => decl.inflect(info, &mut buf)?,
+                Declension::Adjective(decl) => decl.inflect(info, &mut buf)?,
+                // Nouns don't decline by pronoun declension
+                Declension::Pronoun(_) => return Err(InflectError::IncompatibleDeclension),
             };
         }
 
         buf.finish(&mut word);
-        word
+        Ok(word)
     }
 }
 
 impl NounDeclension {
-    pub(crate) fn inflect(self, info: DeclInfo, buf: &mut InflectionBuf) {
+    pub(crate) fn inflect(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
         // Determine the stress position
         buf.stress = self.stress.pos(info);
 
@@ -46,7 +121,7 @@ impl NounDeclension {
         buf.append_to_ending(self.find_ending(info).as_str());
 
         if self.flags.has_circle() {
-            self.apply_unique_alternation(info, buf);
+            self.apply_unique_alternation(info, buf)?;
         }
 
         // Special case for stem type 8: endings from the table may start with 'я', while
@@ -62,11 +137,11 @@ impl NounDeclension {
 
         // The е/ё alternation is handled more efficiently in apply_unique_alternation()
         if self.flags.has_alternating_yo() && !self.flags.has_circle() {
-            self.apply_ye_yo_alternation(info, buf);
+            self.apply_ye_yo_alternation(info, buf)?;
         }
 
         if self.flags.has_star() {
-            self.apply_vowel_alternation(info, buf);
+            self.apply_vowel_alternation(info, buf)?;
         }
 
         // TODO: Move the stress to the ending, if needed
@@ -74,11 +149,17 @@ impl NounDeclension {
             let max_stress_pos = (buf.stem_len + 1).min(buf.len);
             let candidates = unsafe { buf.as_slice().get_unchecked(..max_stress_pos) };
 
-            buf.stress_at = candidates.iter().rposition(|x| x.is_vowel()).unwrap() + 1;
+            let vowel = candidates.iter().rposition(|x| x.is_vowel());
+            buf.stress_at = vowel.ok_or(InflectError::NoVowelInStem)? + 1;
         }
+        Ok(())
     }
 
-    fn apply_unique_alternation(self, info: DeclInfo, buf: &mut InflectionBuf) {
+    fn apply_unique_alternation(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
         use Utf8Letter::*;
 
         match buf.stem_mut() {
@@ -94,7 +175,7 @@ impl NounDeclension {
                             ""
                         } else {
                             // Don't override if (1) flag already did (господин - господа)
-                            if self.flags.has_circled_one() { return } else { "е" }
+                            if self.flags.has_circled_one() { return Ok(()) } else { "е" }
                         });
                     }
                 }
@@ -219,11 +300,16 @@ impl NounDeclension {
                     }
                 }
             },
-            _ => unimplemented!(),
+            _ => return Err(InflectError::InvalidUniqueAlternation),
         };
+        Ok(())
     }
 
-    fn apply_ye_yo_alternation(self, _info: DeclInfo, buf: &mut InflectionBuf) {
+    fn apply_ye_yo_alternation(
+        self,
+        _info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
         // If there's a 'ё' in the stem:
         if let Some(yo) = buf.stem_mut().iter_mut().find(|x| **x == Utf8Letter::Ё) {
             // SAFETY: The InflectionBuf isn't modified between here and the assignment of yo.
@@ -238,7 +324,7 @@ impl NounDeclension {
 
             // Find the LAST unstressed 'е' in the stem
             let Some(ye) = buf.stem_mut().iter_mut().rfind(|x| **x == Utf8Letter::Е) else {
-                todo!("Handle absence of 'е' in the stem?")
+                return Err(InflectError::NoAlternatingYe);
             };
             // SAFETY: The InflectionBuf isn't modified between here and the assignment of ye.
             let ye = unsafe { &mut *&raw mut *ye };
@@ -271,24 +357,22 @@ impl NounDeclension {
                 buf.set_stress_at(ye);
             }
         }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::word::Accent;
+    use crate::{noun::NewNounError, word::Accent};
 
     fn decl(word: &str, info: &str) -> [String; 2] {
-        let mut stem: WordBuf = word.parse().unwrap();
-        let _ty = NounStemType::identify_trim(&mut stem);
-
-        let noun = Noun { stem, info: info.parse().unwrap() };
+        let paradigm = Noun::from_word(word, info.parse().unwrap()).unwrap().paradigm();
 
         Number::VALUES.map(|number| {
             Case::VALUES
                 .map(|case| {
-                    let word = noun.inflect(case.into(), number);
+                    let word = &paradigm[(case, number)];
                     word.display().accent(Accent::explicit(Accent::ACUTE)).to_string()
                 })
                 .join(", ")
@@ -298,7 +382,7 @@ mod tests {
     extern crate test;
     #[bench]
     fn bench(b: &mut test::Bencher) {
-        let noun = Noun { stem: "метл".parse().unwrap(), info: "ж 1*d, ё".parse().unwrap() };
+        let noun = Noun::from_word("метла́", "ж 1*d, ё".parse().unwrap()).unwrap();
         b.iter(|| {
             let mut s = String::new();
             for number in Number::VALUES {
@@ -850,4 +934,82 @@ mod tests {
         //     "",
         // ]);
     }
+
+    #[test]
+    fn from_word() {
+        let new = |word: &str, info: &str| Noun::from_word(word, info.parse().unwrap());
+
+        // Stem type 8 is accepted for soft and sibilant stems
+        assert!(new("две́рь", "ж 8e").is_ok());
+        assert!(new("но́чь", "ж 8e").is_ok());
+        assert!(new("вре́мя", "с 8°c").is_ok());
+        // Adjective declension and indeclinable nouns
+        assert!(new("столо́вая", "ж <п 1a>").is_ok());
+        assert!(new("ко́фе", "м 0").is_ok());
+
+        assert_eq!(new("стол", "м 2b"), Err(NewNounError::NotMatchingStemType));
+        assert_eq!(new("рука́", "ж 1f′"), Err(NewNounError::NotMatchingStemType));
+        assert_eq!(new("столо́вая", "ж <п 2a>"), Err(NewNounError::NotMatchingStemType));
+        assert_eq!(new("стул", "м 8b"), Err(NewNounError::NotMatchingStemType));
+        assert_eq!(
+            new("стол", "м 1b"),
+            Ok(Noun::from_stem("стол".parse().unwrap(), "м 1b".parse().unwrap()))
+        );
+        assert_eq!(new("руки", "ж 3f′"), Err(NewNounError::InvalidStem));
+        assert_eq!(new("подъ", "м 1a"), Err(NewNounError::InvalidStem));
+    }
+
+    #[test]
+    fn secondary_cases() {
+        let forms = |word: &str, info: &str| {
+            let noun = Noun::from_word(word, info.parse().unwrap()).unwrap();
+            [CaseEx::Genitive, CaseEx::Partitive, CaseEx::Prepositional, CaseEx::Locative].map(
+                |case| {
+                    let word = noun.inflect(case, Number::Singular);
+                    word.display().accent(Accent::explicit(Accent::ACUTE)).to_string()
+                },
+            )
+        };
+
+        // Р2: partitive singular
+        assert_eq!(forms("ча́й", "м 6c, Р2"), ["ча́я", "ча́ю", "ча́е", "ча́е"]);
+        assert_eq!(forms("са́хар", "м 1a, Р2"), ["са́хара", "са́хару", "са́харе", "са́харе"]);
+        // П2: locative singular, always stressed on the ending
+        assert_eq!(forms("лес", "м 1c, П2 (в)"), ["ле́са", "ле́са", "ле́се", "лесу́"]);
+        assert_eq!(forms("у́гол", "м 1*b, П2 (в, на)"), ["угла́", "угла́", "угле́", "углу́"]);
+        assert_eq!(forms("кра́й", "м 6c, П2 (на)"), ["кра́я", "кра́я", "кра́е", "краю́"]);
+        assert_eq!(forms("кро́вь", "ж 8e, П2 (в)"), ["кро́ви", "кро́ви", "кро́ви", "крови́"]);
+        // Both marks
+        assert_eq!(forms("лёд", "м 1*b, Р2, П2 (на)"), ["льда́", "льду́", "льде́", "льду́"]);
+
+        // Nouns without the marks use the primary cases
+        assert_eq!(forms("сто́л", "м 1b"), ["стола́", "стола́", "столе́", "столе́"]);
+
+        // Plural forms are the same as primary cases' ones
+        let noun = Noun::from_word("лес", "м 1c, П2 (в)".parse().unwrap()).unwrap();
+        assert_eq!(noun.inflect(CaseEx::Locative, Number::Plural).as_str(), "лесах");
+    }
+
+    #[test]
+    fn try_inflect() {
+        let try_inflect = |word: &str, info: &str| {
+            let noun = Noun::from_word(word, info.parse().unwrap()).unwrap();
+            noun.try_inflect(CaseEx::Genitive, Number::Plural).map(|x| x.as_str().to_owned())
+        };
+
+        assert_eq!(try_inflect("бое́ц", "мо 5*b"), Ok("бойцов".to_owned()));
+        // ° alternation with an unknown suffix
+        assert_eq!(try_inflect("сто́л", "м 1°b"), Err(InflectError::InvalidUniqueAlternation));
+        // * alternation of a vowel that can't alternate
+        assert_eq!(try_inflect("бара́н", "м 1*a"), Err(InflectError::InvalidVowelAlternation));
+        // ё alternation without an 'е' in the stem
+        assert_eq!(try_inflect("со́н", "м 1b, ё"), Err(InflectError::NoAlternatingYe));
+
+        // Declensions not applicable to nouns
+        let mut info: NounInfo = "м 1a".parse().unwrap();
+        info.declension = Some(Declension::Pronoun("1a".parse().unwrap()));
+        let noun = Noun::from_stem("стол".parse().unwrap(), info);
+        let result = noun.try_inflect(CaseEx::Nominative, Number::Singular);
+        assert_eq!(result, Err(InflectError::IncompatibleDeclension));
+    }
 }
@@ -0,0 +1,77 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// A set of noun flags, marking nouns with secondary case forms and count forms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::noun::{NounFlags, NounInfo};
+    ///
+    /// let info: NounInfo = "м 1c, Р2, П2 (в)".parse().unwrap();
+    /// assert_eq!(info.flags, NounFlags::PARTITIVE | NounFlags::LOCATIVE | NounFlags::LOCATIVE_IN);
+    /// ```
+    #[derive(Debug, Copy, Eq)]
+    #[derive_const(Clone)]
+    pub struct NounFlags: u8 {
+        /// The Р2 mark, indicating a distinct partitive (second genitive) singular form, ending
+        /// in -у/-ю and stressed like the dative singular form: ча́ю, са́хару.
+        const PARTITIVE   = 1 << 0;
+        /// The П2 mark, indicating a distinct locative (second prepositional) singular form, with
+        /// the stress on the ending: в лесу́, на мосту́, в крови́.
+        const LOCATIVE    = 1 << 1;
+        /// The locative form is used with the preposition 'в': в лесу́.
+        const LOCATIVE_IN = 1 << 2;
+        /// The locative form is used with the preposition 'на': на мосту́.
+        const LOCATIVE_ON = 1 << 3;
+        /// The Сч mark, indicating a distinct count form used after numerals 2, 3 and 4, that
+        /// differs from the genitive singular form in stress: два часа́, три шага́.
+        const COUNT_FORM  = 1 << 4;
+    }
+}
+
+impl NounFlags {
+    /// Returns `true` if this contains the Р2 mark.
+    pub const fn has_partitive(self) -> bool {
+        self.intersects(Self::PARTITIVE)
+    }
+    /// Returns `true` if this contains the П2 mark.
+    pub const fn has_locative(self) -> bool {
+        self.intersects(Self::LOCATIVE)
+    }
+    /// Returns `true` if this contains the Сч mark.
+    pub const fn has_count_form(self) -> bool {
+        self.intersects(Self::COUNT_FORM)
+    }
+
+    /// Returns the preferred preposition used with the locative form ('в' or 'на'),
+    /// or `None` if the noun has no locative form, or its preposition is not specified.
+    pub const fn locative_preposition(self) -> Option<&'static str> {
+        if !self.has_locative() {
+            return None;
+        }
+        if self.intersects(Self::LOCATIVE_IN) {
+            Some("в")
+        } else if self.intersects(Self::LOCATIVE_ON) {
+            Some("на")
+        } else {
+            None
+        }
+    }
+}
+
+impl const Default for NounFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+impl const PartialEq for NounFlags {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+impl std::hash::Hash for NounFlags {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_u8(self.bits());
+    }
+}
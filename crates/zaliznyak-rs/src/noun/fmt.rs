@@ -1,20 +1,24 @@
 use crate::{
     categories::{Animacy, Gender, GenderEx, Number},
-    declension::{AdjectiveDeclension, DECLENSION_MAX_LEN, Declension, NounDeclension},
-    noun::NounInfo,
+    declension::{
+        AdjectiveDeclension, DECLENSION_MAX_LEN, Declension, NounDeclension, PronounDeclension,
+    },
+    noun::{NounFlags, NounInfo},
     util::UnsafeBuf,
 };
 
-// Longest form: мн. неод. <п 6°*f″/f″①②③, ё—> (50 bytes, 29 chars)
-// Max additions: мн. неод. <п —> (+24 bytes, +15 chars)
-pub const NOUN_INFO_MAX_LEN: usize = DECLENSION_MAX_LEN + 24;
+// Longest form: мн. неод. <п 6°*f″/f″①②③, ё—>, Р2, П2 (в, на), Сч (77 bytes, 49 chars)
+// Max additions: мн. неод. <п —>, Р2, П2 (в, на), Сч (+51 bytes, +35 chars)
+// (Pronoun declensions add 'мс ' instead of 'п ', but are at least 5 bytes shorter: no '/f″')
+pub const NOUN_INFO_MAX_LEN: usize = DECLENSION_MAX_LEN + 51;
 
 impl NounInfo {
     pub const fn fmt_to<'a>(&self, dst: &'a mut [u8; NOUN_INFO_MAX_LEN]) -> &'a mut str {
         let mut dst = UnsafeBuf::new(dst);
 
         // Include brackets if the declension is non-noun
-        let mut need_brackets = matches!(self.declension, Some(Declension::Adjective(_)));
+        let mut need_brackets =
+            matches!(self.declension, Some(Declension::Adjective(_) | Declension::Pronoun(_)));
 
         // If it's a plurale tantum, append 'мн.'
         if self.tantum == Some(Number::Plural) {
@@ -23,7 +27,7 @@ impl NounInfo {
 
             // If gender and animacy won't be specified in brackets (0 or adjective declension),
             //   then include the animacy right after 'мн.': 'мн. неод.', 'мн. одуш.'.
-            if matches!(self.declension, None | Some(Declension::Adjective(_))) {
+            if !matches!(self.declension, Some(Declension::Noun(_))) {
                 dst.push_str(match self.animacy {
                     Animacy::Inanimate => " неод.",
                     Animacy::Animate => " одуш.",
@@ -71,8 +75,11 @@ impl NounInfo {
                     // Format the noun declension
                     dst.push_fmt2(decl, NounDeclension::fmt_to);
                 },
-                Declension::Pronoun(_) => {
-                    unimplemented!(); // Nouns don't decline by pronoun declension
+                Declension::Pronoun(decl) => {
+                    // Nouns don't decline by pronoun declension, and such info can't be parsed
+                    //   back. Still, format it in brackets, so that Display never panics.
+                    dst.push_str("мс ");
+                    dst.push_fmt2(decl, PronounDeclension::fmt_to);
                 },
                 Declension::Adjective(decl) => {
                     // Append 'п ' prefix
@@ -93,6 +100,34 @@ impl NounInfo {
             dst.push('>');
         }
 
+        // Append secondary case marks: ', Р2', ', П2 (в, на)'
+        if self.flags.has_partitive() {
+            dst.push_str(", Р2");
+        }
+        if self.flags.has_locative() {
+            dst.push_str(", П2");
+
+            let prepositions =
+                self.flags.intersection(NounFlags::LOCATIVE_IN.union(NounFlags::LOCATIVE_ON));
+            if !prepositions.is_empty() {
+                dst.push_str(" (");
+                if self.flags.intersects(NounFlags::LOCATIVE_IN) {
+                    dst.push('в');
+                    if self.flags.intersects(NounFlags::LOCATIVE_ON) {
+                        dst.push_str(", ");
+                    }
+                }
+                if self.flags.intersects(NounFlags::LOCATIVE_ON) {
+                    dst.push_str("на");
+                }
+                dst.push(')');
+            }
+        }
+        // Append count form mark: ', Сч'
+        if self.flags.has_count_form() {
+            dst.push_str(", Сч");
+        }
+
         dst.finish()
     }
 }
@@ -109,8 +144,9 @@ mod tests {
     use crate::{
         declension::{
             AdjectiveDeclension, AdjectiveStemType, DeclensionFlags, NounDeclension, NounStemType,
+            PronounDeclension, PronounStemType,
         },
-        stress::{AdjectiveStress, NounStress},
+        stress::{AdjectiveStress, NounStress, PronounStress},
     };
 
     #[test]
@@ -122,6 +158,7 @@ mod tests {
                 declension_gender: Gender::Feminine,
                 animacy: Animacy::Animate,
                 tantum: None,
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type4,
                     stress: NounStress::B,
@@ -137,6 +174,7 @@ mod tests {
                 declension_gender: Gender::Masculine,
                 animacy: Animacy::Inanimate,
                 tantum: None,
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type6,
                     stress: NounStress::Fp,
@@ -154,6 +192,7 @@ mod tests {
                 declension_gender: Gender::Feminine,
                 animacy: Animacy::Animate,
                 tantum: None,
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type2,
                     stress: NounStress::C,
@@ -169,6 +208,7 @@ mod tests {
                 declension_gender: Gender::Neuter,
                 animacy: Animacy::Animate,
                 tantum: Some(Number::Singular),
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type2,
                     stress: NounStress::A,
@@ -184,6 +224,7 @@ mod tests {
                 declension_gender: Gender::Masculine,
                 animacy: Animacy::Animate,
                 tantum: Some(Number::Plural),
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type3,
                     stress: NounStress::A,
@@ -202,6 +243,7 @@ mod tests {
                 declension_gender: Gender::Neuter,
                 animacy: Animacy::Inanimate,
                 tantum: Some(Number::Plural),
+                flags: NounFlags::empty(),
                 declension: None,
             }
             .to_string(),
@@ -214,6 +256,7 @@ mod tests {
                 declension_gender: Gender::Neuter,
                 animacy: Animacy::Inanimate,
                 tantum: Some(Number::Plural),
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Adjective(AdjectiveDeclension {
                     stem_type: AdjectiveStemType::Type1,
                     stress: AdjectiveStress::B,
@@ -223,5 +266,36 @@ mod tests {
             .to_string(),
             "мн. неод. <п 1b>",
         );
+
+        // Nouns don't decline by pronoun declension, but it's still formatted in brackets
+        assert_eq!(
+            NounInfo {
+                gender: GenderEx::Masculine,
+                declension_gender: Gender::Masculine,
+                animacy: Animacy::Inanimate,
+                tantum: None,
+                flags: NounFlags::empty(),
+                declension: Some(Declension::Pronoun(PronounDeclension {
+                    stem_type: PronounStemType::Type1,
+                    stress: PronounStress::A,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }
+            .to_string(),
+            "м <мс 1a>",
+        );
+
+        // Secondary case marks
+        for s in [
+            "м 1c, Р2",
+            "м 1c, П2",
+            "м 1*b, Р2, П2 (на)",
+            "ж 8e, П2 (в, на)",
+            "м 1c—, П2 (в)",
+            "м 1c, Р2, П2 (в), Сч",
+            "м 3c, Сч",
+        ] {
+            assert_eq!(s.parse::<NounInfo>().unwrap().to_string(), s);
+        }
     }
 }
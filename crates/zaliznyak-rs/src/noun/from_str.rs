@@ -3,7 +3,7 @@ use crate::{
     declension::{
         AdjectiveDeclension, Declension, DeclensionKind, NounDeclension, ParseDeclensionError,
     },
-    noun::NounInfo,
+    noun::{NounFlags, NounInfo},
     util::{PartialFromStr, UnsafeParser},
     word::Utf8Letter,
 };
@@ -22,6 +22,8 @@ pub enum ParseNounInfoError {
     NoAnimacy,
     #[error("both plurale and singulare tantums were specified")]
     BothTantums,
+    #[error("invalid locative prepositions")]
+    InvalidPrepositions,
     #[error("invalid format")]
     Invalid,
 }
@@ -173,12 +175,43 @@ impl const PartialFromStr for NounInfo {
             }
         }
 
+        // Parse ', Р2' and ', П2' secondary case marks, and ', Сч' count form mark
+        let mut flags = NounFlags::empty();
+
+        if parser.skip_str(", Р2") {
+            flags = flags.union(NounFlags::PARTITIVE);
+        }
+        if parser.skip_str(", П2") {
+            flags = flags.union(NounFlags::LOCATIVE);
+
+            // Preferred prepositions: ' (в)', ' (на)' or ' (в, на)'
+            if parser.skip_str(" (") {
+                if parser.skip('в') {
+                    flags = flags.union(NounFlags::LOCATIVE_IN);
+                    if parser.skip_str(", на") {
+                        flags = flags.union(NounFlags::LOCATIVE_ON);
+                    }
+                } else if parser.skip_str("на") {
+                    flags = flags.union(NounFlags::LOCATIVE_ON);
+                } else {
+                    return Err(Self::Err::InvalidPrepositions);
+                }
+                if !parser.skip(')') {
+                    return Err(Self::Err::InvalidPrepositions);
+                }
+            }
+        }
+        if parser.skip_str(", Сч") {
+            flags = flags.union(NounFlags::COUNT_FORM);
+        }
+
         Ok(NounInfo {
             gender,
             declension_gender,
             declension,
             animacy: animacy.ok_or(Self::Err::NoAnimacy)?,
             tantum,
+            flags,
         })
     }
 }
@@ -209,6 +242,7 @@ mod tests {
                 declension_gender: Gender::Masculine,
                 animacy: Animacy::Animate,
                 tantum: None,
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type3,
                     stress: NounStress::B,
@@ -223,6 +257,7 @@ mod tests {
                 declension_gender: Gender::Neuter,
                 animacy: Animacy::Inanimate,
                 tantum: Some(Number::Singular),
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type4,
                     stress: NounStress::A,
@@ -237,6 +272,7 @@ mod tests {
                 declension_gender: Gender::Feminine,
                 animacy: Animacy::Animate,
                 tantum: None,
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type6,
                     stress: NounStress::F,
@@ -253,6 +289,7 @@ mod tests {
                 declension_gender: Gender::Feminine,
                 animacy: Animacy::Animate,
                 tantum: None,
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type5,
                     stress: NounStress::C,
@@ -267,6 +304,7 @@ mod tests {
                 declension_gender: Gender::Masculine,
                 animacy: Animacy::Animate,
                 tantum: Some(Number::Plural),
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Noun(NounDeclension {
                     stem_type: NounStemType::Type4,
                     stress: NounStress::A,
@@ -283,6 +321,7 @@ mod tests {
                 declension_gender: Gender::Masculine,
                 animacy: Animacy::Animate,
                 tantum: Some(Number::Plural),
+                flags: NounFlags::empty(),
                 declension: None,
             }),
         );
@@ -293,6 +332,7 @@ mod tests {
                 declension_gender: Gender::Masculine,
                 animacy: Animacy::Inanimate,
                 tantum: Some(Number::Plural),
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Adjective(AdjectiveDeclension {
                     stem_type: AdjectiveStemType::Type4,
                     stress: AdjectiveStress::A,
@@ -312,6 +352,7 @@ mod tests {
                 declension_gender: Gender::Neuter,
                 animacy: Animacy::Animate,
                 tantum: Some(Number::Singular),
+                flags: NounFlags::empty(),
                 declension: Some(Declension::Adjective(AdjectiveDeclension {
                     stem_type: AdjectiveStemType::Type3,
                     stress: AdjectiveStress::B,
@@ -319,5 +360,43 @@ mod tests {
                 })),
             }),
         );
+
+        // Secondary case marks
+        assert_eq!(
+            "м 1c, Р2, П2 (в, на)".parse(),
+            Ok(NounInfo {
+                gender: GenderEx::Masculine,
+                declension_gender: Gender::Masculine,
+                animacy: Animacy::Inanimate,
+                tantum: None,
+                flags: NounFlags::PARTITIVE
+                    | NounFlags::LOCATIVE
+                    | NounFlags::LOCATIVE_IN
+                    | NounFlags::LOCATIVE_ON,
+                declension: Some(Declension::Noun(NounDeclension {
+                    stem_type: NounStemType::Type1,
+                    stress: NounStress::C,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }),
+        );
+        let info: NounInfo = "ж 8e, П2 (на)".parse().unwrap();
+        assert_eq!(info.flags, NounFlags::LOCATIVE | NounFlags::LOCATIVE_ON);
+        assert_eq!(info.flags.locative_preposition(), Some("на"));
+        let info: NounInfo = "м 6c, Р2".parse().unwrap();
+        assert_eq!(info.flags, NounFlags::PARTITIVE);
+        assert_eq!(info.flags.locative_preposition(), None);
+        let info: NounInfo = "м 1c, П2 (в), Сч".parse().unwrap();
+        assert_eq!(
+            info.flags,
+            NounFlags::LOCATIVE | NounFlags::LOCATIVE_IN | NounFlags::COUNT_FORM
+        );
+
+        assert_eq!(
+            "м 1c, П2 (под)".parse::<NounInfo>(),
+            Err(ParseNounInfoError::InvalidPrepositions)
+        );
+        assert_eq!("м 1c, П2 (в".parse::<NounInfo>(), Err(ParseNounInfoError::InvalidPrepositions));
+        assert_eq!("м 1c, П2, Р2".parse::<NounInfo>(), Err(ParseNounInfoError::Invalid));
     }
 }
@@ -0,0 +1,158 @@
+use crate::{
+    categories::{Animacy, Gender},
+    declension::{Declension, DeclensionFlags, NounDeclension, NounStemType},
+    noun::{NounFlags, NounInfo},
+    stress::NounStress,
+    word::{Utf8Letter, Utf8LetterSlice},
+};
+
+impl NounInfo {
+    /// Guesses the most likely declension of a noun, that isn't in the dictionary, from its
+    /// nominative singular form. The word may contain stress marks, but they're ignored.
+    ///
+    /// - The gender is determined by the ending: **-а**/**-я** are feminine, **-о**/**-е** are
+    ///   neuter, consonants are masculine. Nouns in **-ь** are masculine, unless they end in
+    ///   **-ость**, **-знь**, or a sibilant consonant (**ночь**, **мышь**).
+    /// - The stress schema is always **a**.
+    /// - The * flag is added to common suffixes with a fleeting vowel: **-ец**, **-ок** after a
+    ///   sibilant consonant (**значок** --- **значка**), and **-ка** after a consonant (**ложка**
+    ///   --- **ложек**). Other words in **-ок** usually keep the vowel: **урок** --- **урока**.
+    /// - The ° flag is added to **-ёнок**/**-онок** (**котёнок** --- **котята**), and
+    ///   **-анин**/**-янин** (**горожанин** --- **горожане**). These nouns are also animate.
+    /// - Loanwords ending in **-и**, **-у**, **-ю**, **-э** are indeclinable and neuter: **такси**,
+    ///   **меню**.
+    ///
+    /// Returns `None` if the word isn't a valid lowercase Cyrillic word, or its stem couldn't be
+    /// identified. See also [`NounGuesser`][crate::dictionary::NounGuesser], which learns the
+    /// declensions of word endings from a dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::noun::NounInfo;
+    ///
+    /// assert_eq!(NounInfo::guess("смартфон").unwrap().to_string(), "м 1a");
+    /// assert_eq!(NounInfo::guess("флешка").unwrap().to_string(), "ж 3*a");
+    /// assert_eq!(NounInfo::guess("бобрёнок").unwrap().to_string(), "мо 3°a");
+    /// assert_eq!(NounInfo::guess("вайфай").unwrap().to_string(), "м 6a");
+    /// assert_eq!(NounInfo::guess("эмодзи").unwrap().to_string(), "с 0");
+    /// ```
+    #[must_use]
+    pub fn guess(word: &str) -> Option<Self> {
+        let letters: Vec<Utf8Letter> = word
+            .chars()
+            .filter(|&ch| ch != '\u{301}')
+            .map(Utf8Letter::from_char)
+            .collect::<Option<_>>()?;
+        let word = letters.as_str();
+
+        let mut info = Self {
+            declension: None,
+            declension_gender: Gender::Neuter,
+            gender: Gender::Neuter.into(),
+            animacy: Animacy::Inanimate,
+            tantum: None,
+            flags: NounFlags::empty(),
+        };
+
+        // Loanwords ending in these vowels don't decline: такси, кенгуру, меню, каноэ
+        if word.ends_with(['и', 'у', 'ю', 'э']) {
+            return Some(info);
+        }
+
+        let (stem, mut stem_type) = NounStemType::identify(&letters)?;
+        let mut flags = DeclensionFlags::empty();
+
+        let gender = match letters.last()? {
+            Utf8Letter::А | Utf8Letter::Я => Gender::Feminine,
+            Utf8Letter::О | Utf8Letter::Е | Utf8Letter::Ё => Gender::Neuter,
+            Utf8Letter::Ь => {
+                let &last = stem.last()?;
+                if last.is_hissing() || word.ends_with("ость") || word.ends_with("знь") {
+                    stem_type = NounStemType::Type8;
+                    Gender::Feminine
+                } else {
+                    Gender::Masculine
+                }
+            },
+            _ => Gender::Masculine,
+        };
+
+        if ["ёнок", "онок", "анин", "янин"].iter().any(|x| word.ends_with(x)) {
+            // котёнок, медвежонок, горожанин, but not магазин
+            flags = DeclensionFlags::CIRCLE;
+            info.animacy = Animacy::Animate;
+        } else if ["жок", "чок", "шок", "щок", "ец"].iter().any(|x| word.ends_with(x))
+            && letters[..letters.len() - 2].iter().any(|x| x.is_vowel())
+        {
+            // кружок, значок, огурец, but not шок, урок or человек
+            flags = DeclensionFlags::STAR;
+        } else if let [.., before, Utf8Letter::К, Utf8Letter::А] = *letters
+            && before.is_consonant()
+        {
+            // ложка, сумка, but not рука
+            flags = DeclensionFlags::STAR;
+        }
+
+        let declension = NounDeclension { stem_type, stress: NounStress::A, flags };
+        info.declension = Some(Declension::Noun(declension));
+        info.declension_gender = gender;
+        info.gender = gender.into();
+        Some(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{CaseEx, Number},
+        noun::Noun,
+    };
+
+    #[test]
+    fn guess() {
+        let guess = |word| NounInfo::guess(word).unwrap().to_string();
+
+        assert_eq!(guess("смартфо́н"), "м 1a");
+        assert_eq!(guess("гаджет"), "м 1a");
+        assert_eq!(guess("роутер"), "м 1a");
+        assert_eq!(guess("девайс"), "м 1a");
+        assert_eq!(guess("лайк"), "м 3a");
+        assert_eq!(guess("блогер"), "м 1a");
+        assert_eq!(guess("флешка"), "ж 3*a");
+        assert_eq!(guess("рука"), "ж 3a");
+        assert_eq!(guess("стартап"), "м 1a");
+        assert_eq!(guess("окно"), "с 1a");
+        assert_eq!(guess("мышь"), "ж 8a");
+        assert_eq!(guess("сложность"), "ж 8a");
+        assert_eq!(guess("файервол"), "м 1a");
+        assert_eq!(guess("пароль"), "м 2a");
+        assert_eq!(guess("огурец"), "м 5*a");
+        assert_eq!(guess("значок"), "м 3*a");
+        assert_eq!(guess("чек"), "м 3a");
+        assert_eq!(guess("урок"), "м 3a");
+        assert_eq!(guess("человек"), "м 3a");
+        assert_eq!(guess("котёнок"), "мо 3°a");
+        assert_eq!(guess("горожанин"), "мо 1°a");
+        assert_eq!(guess("магазин"), "м 1a");
+        assert_eq!(guess("такси"), "с 0");
+
+        assert_eq!(NounInfo::guess("Wi-Fi"), None);
+        assert_eq!(NounInfo::guess(""), None);
+    }
+
+    #[test]
+    fn inflect() {
+        let inflect = |word: &str, case, number| {
+            let noun = Noun::from_word(word, NounInfo::guess(word).unwrap()).unwrap();
+            noun.inflect(case, number).as_str().to_owned()
+        };
+
+        assert_eq!(inflect("фле́шка", CaseEx::Genitive, Number::Plural), "флешек");
+        assert_eq!(inflect("бобрёнок", CaseEx::Nominative, Number::Plural), "бобрята");
+        assert_eq!(inflect("сельча́нин", CaseEx::Genitive, Number::Plural), "сельчан");
+        assert_eq!(inflect("смартфо́н", CaseEx::Instrumental, Number::Singular), "смартфоном");
+        assert_eq!(inflect("сло́жность", CaseEx::Dative, Number::Singular), "сложности");
+    }
+}
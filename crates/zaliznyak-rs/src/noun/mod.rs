@@ -1,14 +1,17 @@
 use crate::{
     categories::{Animacy, Gender, GenderEx, Number},
     declension::Declension,
-    word::WordBuf,
+    word::{Word, WordBuf},
 };
 use thiserror::Error;
 
 mod declension;
+mod flags;
 mod fmt;
 mod from_str;
+mod guess;
 
+pub use flags::*;
 pub use from_str::*;
 
 #[derive(Debug, Clone, Eq, Hash)]
@@ -26,6 +29,7 @@ pub struct NounInfo {
     pub gender: GenderEx,
     pub animacy: Animacy,
     pub tantum: Option<Number>,
+    pub flags: NounFlags,
 }
 
 #[derive(Debug, Error, Copy, Eq, Hash)]
@@ -41,4 +45,49 @@ impl Noun {
     pub const fn from_stem(stem: WordBuf, info: NounInfo) -> Self {
         Self { stem, info }
     }
+    /// Constructs a noun from its nominative singular form (or nominative plural, for pluralia
+    /// tantum), identifying its stem according to the noun's declension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NewNounError::InvalidStem`] if the word is not a valid stressed word or its stem
+    /// couldn't be identified, and [`NewNounError::NotMatchingStemType`] if the identified stem
+    /// type doesn't match the declension's stem type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{
+    ///     categories::{CaseEx, Number},
+    ///     noun::{NewNounError, Noun},
+    /// };
+    ///
+    /// let noun = Noun::from_word("бое́ц", "мо 5*b".parse().unwrap()).unwrap();
+    /// assert_eq!(noun.inflect(CaseEx::Genitive, Number::Singular).as_str(), "бойца");
+    ///
+    /// let result = Noun::from_word("бое́ц", "мо 1*b".parse().unwrap());
+    /// assert_eq!(result, Err(NewNounError::NotMatchingStemType));
+    /// ```
+    pub fn from_word(word: &str, info: NounInfo) -> Result<Self, NewNounError> {
+        let mut stem: WordBuf = word.parse().or(Err(NewNounError::InvalidStem))?;
+
+        // Indeclinable nouns keep the entire word as the stem
+        if let Some(decl) = info.declension {
+            let (matches, _) = decl.identify_trim(&mut stem).ok_or(NewNounError::InvalidStem)?;
+            if !matches {
+                return Err(NewNounError::NotMatchingStemType);
+            }
+        }
+        Ok(Self { stem, info })
+    }
+    /// Returns the noun's stem.
+    #[must_use]
+    pub const fn stem(&self) -> Word<'_> {
+        self.stem.borrow()
+    }
+    /// Returns the noun's grammatical info.
+    #[must_use]
+    pub const fn info(&self) -> NounInfo {
+        self.info
+    }
 }
@@ -0,0 +1,342 @@
+use crate::{
+    categories::{Animacy, DeclInfo, Gender, Number},
+    noun::Noun,
+    numeral::{CountingNumeral, Forms, select_form},
+    phrase::{CountForm, NounPhrase},
+};
+use std::sync::LazyLock;
+
+/// A cardinal numeral (количественное числительное), spelled out in words.
+///
+/// Cardinals are declined by case, and agree with the counted noun in gender (один/одно/одна,
+/// два/две) and, in the accusative case, in animacy (вижу один стол, but вижу одного кота).
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Animacy, Case, DeclInfo, Gender, Number},
+///     numeral::Cardinal,
+/// };
+///
+/// let n = Cardinal::new(2021);
+/// assert_eq!(n.to_string(), "две тысячи двадцать один");
+///
+/// let info = DeclInfo {
+///     case: Case::Instrumental,
+///     number: Number::Singular,
+///     gender: Gender::Feminine,
+///     animacy: Animacy::Inanimate,
+/// };
+/// assert_eq!(n.inflect(info), "двумя тысячами двадцатью одной");
+/// ```
+///
+/// To inflect the counted noun phrase accordingly, use
+/// [`NounPhrase::inflect_count_words`], with the phrase's
+/// [`agreement`][NounPhrase::agreement] for the numeral's gender and animacy:
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Case, Number},
+///     noun::Noun,
+///     numeral::Cardinal,
+///     phrase::NounPhrase,
+/// };
+///
+/// let noun = Noun::from_word("ко́шка", "жо 3*a".parse().unwrap()).unwrap();
+/// let phrase = NounPhrase::new(noun);
+///
+/// let n = Cardinal::new(2);
+/// let info = phrase.agreement(Case::Accusative.into(), Number::Plural);
+/// let noun = &phrase.inflect_count_words(n.value(), Case::Accusative)[0];
+/// assert_eq!(format!("{} {}", n.inflect(info), noun.as_str()), "двух кошек");
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub struct Cardinal {
+    value: u64,
+}
+
+impl Cardinal {
+    /// Constructs a cardinal numeral with the specified value.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        Self { value }
+    }
+    /// Returns the numeral's value.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Returns the words of the numeral in the specified case, agreeing with a noun of the
+    /// specified gender and animacy. Plural number is only used by `одни` (одни ножницы).
+    #[must_use]
+    pub fn inflect(self, info: DeclInfo) -> String {
+        let mut words: Vec<String> = Vec::new();
+        let mut rest = self.value;
+
+        for &(scale, ref noun) in SCALE_NOUNS.iter() {
+            let count = rest / scale;
+            rest %= scale;
+            if count == 0 {
+                continue;
+            }
+
+            // The scale nouns are inanimate, so the numerals before them are too
+            let count_info = DeclInfo {
+                number: Number::Singular,
+                gender: noun.info().gender.normalize(),
+                animacy: Animacy::Inanimate,
+                ..info
+            };
+            push_triple(&mut words, count as u16, count_info);
+
+            let noun = &NounPhrase::new(noun.clone()).inflect_count_words(count, info.case)[0];
+            words.push(noun.as_str().to_owned());
+        }
+
+        if rest > 0 {
+            // Only standalone 2, 3 and 4 are animate in the accusative case: вижу двух котов,
+            //   but вижу двадцать два кота. Numerals ending in 1 always agree in animacy.
+            let mut info = info;
+            let standalone = rest == self.value && rest <= 4;
+            if !standalone && (rest % 10 != 1 || rest % 100 == 11) {
+                info.animacy = Animacy::Inanimate;
+            }
+            push_triple(&mut words, rest as u16, info);
+        } else if self.value == 0 {
+            words.push(ZERO[info.case as usize].to_owned());
+        }
+
+        words.join(" ")
+    }
+}
+
+impl CountingNumeral for Cardinal {
+    fn inflect(&self, info: DeclInfo) -> String {
+        Self::inflect(*self, info)
+    }
+    fn count_form(&self) -> CountForm {
+        CountForm::of(self.value)
+    }
+    fn is_animate(&self) -> bool {
+        (2..=4).contains(&self.value)
+    }
+}
+
+impl std::fmt::Display for Cardinal {
+    /// Formats the numeral in the nominative case, masculine gender: `двадцать один`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inflect(DeclInfo::default()))
+    }
+}
+
+/// Pushes the words of a number from 1 to 999 in the specified case, gender and animacy.
+pub(super) fn push_triple(words: &mut Vec<String>, n: u16, info: DeclInfo) {
+    let case = info.case as usize;
+    let (hundreds, tens, units) = (n / 100, n / 10 % 10, n % 10);
+
+    if hundreds > 0 {
+        words.push(HUNDREDS[hundreds as usize - 1][case].to_owned());
+    }
+    if tens == 1 {
+        words.push(TEENS[units as usize][case].to_owned());
+        return;
+    }
+    if tens > 1 {
+        words.push(TENS[tens as usize - 2][case].to_owned());
+    }
+    if units == 0 {
+        return;
+    }
+
+    let forms = match (units, info.number, info.gender) {
+        (1, Number::Plural, _) => &ONE[3],
+        (1, _, gender) => &ONE[gender as usize],
+        (2, _, Gender::Feminine) => &TWO[1],
+        (2, _, _) => &TWO[0],
+        _ => &UNITS[units as usize - 3],
+    };
+    words.push(select_form(forms, info).to_owned());
+}
+
+const SCALES: [(u64, &str, &str); 6] = [
+    (1_000_000_000_000_000_000, "квинтиллио́н", "м 1a"),
+    (1_000_000_000_000_000, "квадриллио́н", "м 1a"),
+    (1_000_000_000_000, "триллио́н", "м 1a"),
+    (1_000_000_000, "миллиа́рд", "м 1a"),
+    (1_000_000, "миллио́н", "м 1a"),
+    (1_000, "ты́сяча", "ж 4a"),
+];
+
+static SCALE_NOUNS: LazyLock<Vec<(u64, Noun)>> = LazyLock::new(|| {
+    let noun = |word, info: &str| Noun::from_word(word, info.parse().unwrap()).unwrap();
+    SCALES.iter().map(|&(scale, word, info)| (scale, noun(word, info))).collect()
+});
+
+const ZERO: Forms = ["ноль", "ноля", "нолю", "ноль", "нолём", "ноле"];
+
+#[rustfmt::skip]
+const ONE: [Forms; 4] = [
+    ["один", "одного", "одному", "один", "одним", "одном"],
+    ["одно", "одного", "одному", "одно", "одним", "одном"],
+    ["одна", "одной", "одной", "одну", "одной", "одной"],
+    ["одни", "одних", "одним", "одни", "одними", "одних"],
+];
+#[rustfmt::skip]
+const TWO: [Forms; 2] = [
+    ["два", "двух", "двум", "два", "двумя", "двух"],
+    ["две", "двух", "двум", "две", "двумя", "двух"],
+];
+#[rustfmt::skip]
+const UNITS: [Forms; 7] = [
+    ["три", "трёх", "трём", "три", "тремя", "трёх"],
+    ["четыре", "четырёх", "четырём", "четыре", "четырьмя", "четырёх"],
+    ["пять", "пяти", "пяти", "пять", "пятью", "пяти"],
+    ["шесть", "шести", "шести", "шесть", "шестью", "шести"],
+    ["семь", "семи", "семи", "семь", "семью", "семи"],
+    ["восемь", "восьми", "восьми", "восемь", "восемью", "восьми"],
+    ["девять", "девяти", "девяти", "девять", "девятью", "девяти"],
+];
+#[rustfmt::skip]
+const TEENS: [Forms; 10] = [
+    ["десять", "десяти", "десяти", "десять", "десятью", "десяти"],
+    ["одиннадцать", "одиннадцати", "одиннадцати", "одиннадцать", "одиннадцатью", "одиннадцати"],
+    ["двенадцать", "двенадцати", "двенадцати", "двенадцать", "двенадцатью", "двенадцати"],
+    ["тринадцать", "тринадцати", "тринадцати", "тринадцать", "тринадцатью", "тринадцати"],
+    ["четырнадцать", "четырнадцати", "четырнадцати", "четырнадцать", "четырнадцатью", "четырнадцати"],
+    ["пятнадцать", "пятнадцати", "пятнадцати", "пятнадцать", "пятнадцатью", "пятнадцати"],
+    ["шестнадцать", "шестнадцати", "шестнадцати", "шестнадцать", "шестнадцатью", "шестнадцати"],
+    ["семнадцать", "семнадцати", "семнадцати", "семнадцать", "семнадцатью", "семнадцати"],
+    ["восемнадцать", "восемнадцати", "восемнадцати", "восемнадцать", "восемнадцатью", "восемнадцати"],
+    ["девятнадцать", "девятнадцати", "девятнадцати", "девятнадцать", "девятнадцатью", "девятнадцати"],
+];
+#[rustfmt::skip]
+const TENS: [Forms; 8] = [
+    ["двадцать", "двадцати", "двадцати", "двадцать", "двадцатью", "двадцати"],
+    ["тридцать", "тридцати", "тридцати", "тридцать", "тридцатью", "тридцати"],
+    ["сорок", "сорока", "сорока", "сорок", "сорока", "сорока"],
+    ["пятьдесят", "пятидесяти", "пятидесяти", "пятьдесят", "пятьюдесятью", "пятидесяти"],
+    ["шестьдесят", "шестидесяти", "шестидесяти", "шестьдесят", "шестьюдесятью", "шестидесяти"],
+    ["семьдесят", "семидесяти", "семидесяти", "семьдесят", "семьюдесятью", "семидесяти"],
+    ["восемьдесят", "восьмидесяти", "восьмидесяти", "восемьдесят", "восемьюдесятью", "восьмидесяти"],
+    ["девяносто", "девяноста", "девяноста", "девяносто", "девяноста", "девяноста"],
+];
+#[rustfmt::skip]
+const HUNDREDS: [Forms; 9] = [
+    ["сто", "ста", "ста", "сто", "ста", "ста"],
+    ["двести", "двухсот", "двумстам", "двести", "двумястами", "двухстах"],
+    ["триста", "трёхсот", "трёмстам", "триста", "тремястами", "трёхстах"],
+    ["четыреста", "четырёхсот", "четырёмстам", "четыреста", "четырьмястами", "четырёхстах"],
+    ["пятьсот", "пятисот", "пятистам", "пятьсот", "пятьюстами", "пятистах"],
+    ["шестьсот", "шестисот", "шестистам", "шестьсот", "шестьюстами", "шестистах"],
+    ["семьсот", "семисот", "семистам", "семьсот", "семьюстами", "семистах"],
+    ["восемьсот", "восьмисот", "восьмистам", "восемьсот", "восемьюстами", "восьмистах"],
+    ["девятьсот", "девятисот", "девятистам", "девятьсот", "девятьюстами", "девятистах"],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::{Case, CaseEx};
+
+    fn info(case: Case, gender: Gender, animacy: Animacy) -> DeclInfo {
+        DeclInfo { case, number: Number::Singular, gender, animacy }
+    }
+
+    #[test]
+    fn scales() {
+        assert_eq!(SCALE_NOUNS.len(), SCALES.len());
+        for ((scale, noun), (_, word, _)) in SCALE_NOUNS.iter().zip(SCALES) {
+            let nominative = noun.inflect(CaseEx::Nominative, Number::Singular);
+            assert_eq!(nominative.as_str(), word.replace('\u{301}', ""), "{scale}");
+        }
+    }
+
+    #[test]
+    fn nominative() {
+        let cases = [
+            (0, "ноль"),
+            (1, "один"),
+            (7, "семь"),
+            (10, "десять"),
+            (11, "одиннадцать"),
+            (19, "девятнадцать"),
+            (20, "двадцать"),
+            (42, "сорок два"),
+            (99, "девяносто девять"),
+            (100, "сто"),
+            (305, "триста пять"),
+            (1000, "одна тысяча"),
+            (2021, "две тысячи двадцать один"),
+            (5000, "пять тысяч"),
+            (11_000, "одиннадцать тысяч"),
+            (21_000, "двадцать одна тысяча"),
+            (1_000_000, "один миллион"),
+            (2_000_003, "два миллиона три"),
+            (
+                123_456_789,
+                "сто двадцать три миллиона четыреста пятьдесят шесть тысяч семьсот восемьдесят девять",
+            ),
+            (
+                u64::MAX,
+                "восемнадцать квинтиллионов четыреста сорок шесть квадриллионов семьсот сорок четыре \
+                 триллиона семьдесят три миллиарда семьсот девять миллионов пятьсот пятьдесят одна \
+                 тысяча шестьсот пятнадцать",
+            ),
+        ];
+        for (n, s) in cases {
+            assert_eq!(Cardinal::new(n).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn cases() {
+        let m = |case| info(case, Gender::Masculine, Animacy::Inanimate);
+        let n = Cardinal::new(2021);
+        assert_eq!(n.inflect(m(Case::Genitive)), "двух тысяч двадцати одного");
+        assert_eq!(n.inflect(m(Case::Dative)), "двум тысячам двадцати одному");
+        assert_eq!(n.inflect(m(Case::Accusative)), "две тысячи двадцать один");
+        assert_eq!(n.inflect(m(Case::Instrumental)), "двумя тысячами двадцатью одним");
+        assert_eq!(n.inflect(m(Case::Prepositional)), "двух тысячах двадцати одном");
+
+        let n = Cardinal::new(1_548);
+        assert_eq!(n.inflect(m(Case::Instrumental)), "одной тысячей пятьюстами сорока восемью");
+        assert_eq!(n.inflect(m(Case::Accusative)), "одну тысячу пятьсот сорок восемь");
+        let n = Cardinal::new(290);
+        assert_eq!(n.inflect(m(Case::Dative)), "двумстам девяноста");
+        assert_eq!(Cardinal::new(0).inflect(m(Case::Instrumental)), "нолём");
+    }
+
+    #[test]
+    fn gender() {
+        let f = |case| info(case, Gender::Feminine, Animacy::Inanimate);
+        let n = |case| info(case, Gender::Neuter, Animacy::Inanimate);
+        assert_eq!(Cardinal::new(1).inflect(f(Case::Nominative)), "одна");
+        assert_eq!(Cardinal::new(1).inflect(n(Case::Nominative)), "одно");
+        assert_eq!(Cardinal::new(2).inflect(f(Case::Nominative)), "две");
+        assert_eq!(Cardinal::new(2).inflect(n(Case::Nominative)), "два");
+        assert_eq!(Cardinal::new(32).inflect(f(Case::Accusative)), "тридцать две");
+        assert_eq!(Cardinal::new(51).inflect(f(Case::Accusative)), "пятьдесят одну");
+        assert_eq!(Cardinal::new(51).inflect(f(Case::Genitive)), "пятидесяти одной");
+
+        let pl = DeclInfo { number: Number::Plural, ..f(Case::Dative) };
+        assert_eq!(Cardinal::new(1).inflect(pl), "одним");
+    }
+
+    #[test]
+    fn animacy() {
+        let m = info(Case::Accusative, Gender::Masculine, Animacy::Animate);
+        let f = info(Case::Accusative, Gender::Feminine, Animacy::Animate);
+        assert_eq!(Cardinal::new(1).inflect(m), "одного");
+        assert_eq!(Cardinal::new(1).inflect(f), "одну");
+        assert_eq!(Cardinal::new(21).inflect(m), "двадцать одного");
+        assert_eq!(Cardinal::new(2).inflect(m), "двух");
+        assert_eq!(Cardinal::new(4).inflect(f), "четырёх");
+        assert_eq!(Cardinal::new(22).inflect(m), "двадцать два");
+        assert_eq!(Cardinal::new(5).inflect(m), "пять");
+        assert_eq!(Cardinal::new(11).inflect(m), "одиннадцать");
+        assert_eq!(Cardinal::new(1002).inflect(m), "одну тысячу два");
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    categories::{DeclInfo, Gender},
+    numeral::{CountingNumeral, Forms, select_form},
+    phrase::CountForm,
+};
+
+/// A collective numeral (собирательное числительное), from **двое** (2) to **десятеро** (10).
+///
+/// Collectives count people, young animals and pluralia tantum: **двое друзей**, **трое суток**.
+/// In the nominative case, the counted noun takes the genitive plural form, and in oblique cases
+/// it agrees with the numeral in plural.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Animacy, Case, DeclInfo},
+///     numeral::Collective,
+/// };
+///
+/// let n = Collective::new(3).unwrap();
+/// assert_eq!(n.to_string(), "трое");
+///
+/// let info = DeclInfo { case: Case::Accusative, ..DeclInfo::default() };
+/// assert_eq!(n.inflect(info), "трое");
+/// let info = DeclInfo { animacy: Animacy::Animate, ..info };
+/// assert_eq!(n.inflect(info), "троих");
+///
+/// assert_eq!(Collective::new(11), None);
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub struct Collective {
+    value: u8,
+}
+
+impl Collective {
+    /// Constructs a collective numeral with the specified value. Returns `None` if there's no
+    /// collective numeral for it (values outside of the range from 2 to 10).
+    #[must_use]
+    pub const fn new(value: u64) -> Option<Self> {
+        match value {
+            2..=10 => Some(Self { value: value as u8 }),
+            _ => None,
+        }
+    }
+    /// Returns the numeral's value.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.value as u64
+    }
+
+    /// Returns the numeral in the specified case. In the accusative case, agrees with a noun of
+    /// the specified animacy.
+    #[must_use]
+    pub fn inflect(self, info: DeclInfo) -> String {
+        select_form(&COLLECTIVES[self.value as usize - 2], info).to_owned()
+    }
+}
+
+/// The numeral **оба**/**обе** (both). Governs the counted noun just like the cardinal 2 (**оба
+/// стола**), but unlike it, has distinct feminine forms in all cases: **обеих**, **обеим**.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Case, DeclInfo, Gender},
+///     numeral::Both,
+/// };
+///
+/// let info = DeclInfo { case: Case::Dative, gender: Gender::Feminine, ..DeclInfo::default() };
+/// assert_eq!(Both.inflect(info), "обеим");
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Default, Clone, PartialEq)]
+pub struct Both;
+
+impl Both {
+    /// Returns the numeral in the specified case, agreeing with a noun of the specified gender
+    /// and animacy.
+    #[must_use]
+    pub fn inflect(self, info: DeclInfo) -> String {
+        let forms = if info.gender == Gender::Feminine { &BOTH[1] } else { &BOTH[0] };
+        select_form(forms, info).to_owned()
+    }
+}
+
+impl CountingNumeral for Collective {
+    fn inflect(&self, info: DeclInfo) -> String {
+        Self::inflect(*self, info)
+    }
+    fn count_form(&self) -> CountForm {
+        CountForm::Many
+    }
+    fn is_animate(&self) -> bool {
+        true
+    }
+}
+impl CountingNumeral for Both {
+    fn inflect(&self, info: DeclInfo) -> String {
+        Self::inflect(*self, info)
+    }
+    fn count_form(&self) -> CountForm {
+        CountForm::Few
+    }
+    fn is_animate(&self) -> bool {
+        true
+    }
+}
+
+impl std::fmt::Display for Collective {
+    /// Formats the numeral in the nominative case: `двое`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inflect(DeclInfo::default()))
+    }
+}
+impl std::fmt::Display for Both {
+    /// Formats the numeral in the nominative case, masculine gender: `оба`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inflect(DeclInfo::default()))
+    }
+}
+
+#[rustfmt::skip]
+const COLLECTIVES: [Forms; 9] = [
+    ["двое", "двоих", "двоим", "двое", "двоими", "двоих"],
+    ["трое", "троих", "троим", "трое", "троими", "троих"],
+    ["четверо", "четверых", "четверым", "четверо", "четверыми", "четверых"],
+    ["пятеро", "пятерых", "пятерым", "пятеро", "пятерыми", "пятерых"],
+    ["шестеро", "шестерых", "шестерым", "шестеро", "шестерыми", "шестерых"],
+    ["семеро", "семерых", "семерым", "семеро", "семерыми", "семерых"],
+    ["восьмеро", "восьмерых", "восьмерым", "восьмеро", "восьмерыми", "восьмерых"],
+    ["девятеро", "девятерых", "девятерым", "девятеро", "девятерыми", "девятерых"],
+    ["десятеро", "десятерых", "десятерым", "десятеро", "десятерыми", "десятерых"],
+];
+#[rustfmt::skip]
+const BOTH: [Forms; 2] = [
+    ["оба", "обоих", "обоим", "оба", "обоими", "обоих"],
+    ["обе", "обеих", "обеим", "обе", "обеими", "обеих"],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Animacy, Case},
+        phrase::phrase,
+    };
+
+    #[test]
+    fn collective() {
+        let info = |case, animacy| DeclInfo { case, animacy, ..DeclInfo::default() };
+
+        let n = Collective::new(2).unwrap();
+        assert_eq!(n.inflect(info(Case::Instrumental, Animacy::Inanimate)), "двоими");
+        assert_eq!(n.inflect(info(Case::Accusative, Animacy::Inanimate)), "двое");
+        assert_eq!(n.inflect(info(Case::Accusative, Animacy::Animate)), "двоих");
+        let n = Collective::new(4).unwrap();
+        assert_eq!(n.inflect(info(Case::Dative, Animacy::Inanimate)), "четверым");
+        assert_eq!(Collective::new(10).unwrap().to_string(), "десятеро");
+        assert_eq!(Collective::new(1), None);
+
+        let x = phrase(&[], ("студе́нт", "мо 1a"));
+        let n = Collective::new(3).unwrap();
+        assert_eq!(x.inflect_counted(&n, Case::Nominative), "трое студентов");
+        assert_eq!(x.inflect_counted(&n, Case::Accusative), "троих студентов");
+        assert_eq!(x.inflect_counted(&n, Case::Dative), "троим студентам");
+        assert_eq!(x.inflect_counted(&n, Case::Instrumental), "троими студентами");
+
+        let x = phrase(&[], ("но́жницы", "мн. <ж 5a>"));
+        let n = Collective::new(5).unwrap();
+        assert_eq!(x.inflect_counted(&n, Case::Nominative), "пятеро ножниц");
+        assert_eq!(x.inflect_counted(&n, Case::Prepositional), "пятерых ножницах");
+    }
+
+    #[test]
+    fn both() {
+        let x = phrase(&[], ("сто́л", "м 1b"));
+        assert_eq!(x.inflect_counted(&Both, Case::Nominative), "оба стола");
+        assert_eq!(x.inflect_counted(&Both, Case::Genitive), "обоих столов");
+        let x = phrase(&[], ("ко́т", "мо 1b"));
+        assert_eq!(x.inflect_counted(&Both, Case::Accusative), "обоих котов");
+        let x = phrase(&[], ("кни́га", "ж 3a"));
+        assert_eq!(x.inflect_counted(&Both, Case::Nominative), "обе книги");
+        assert_eq!(x.inflect_counted(&Both, Case::Instrumental), "обеими книгами");
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{
+    adjective::Adjective,
+    categories::{Animacy, Case, DeclInfo, Gender, Number},
+    numeral::{Cardinal, CountingNumeral, Forms, Ordinal},
+    phrase::CountForm,
+};
+use std::sync::LazyLock;
+
+/// A fractional numeral (дробное числительное): **две третьих**, **одна пятая**.
+///
+/// The numerator is a feminine cardinal (agreeing with the implied **доля**), and the
+/// denominator is an ordinal: in singular after numerators ending in 1, and in plural otherwise
+/// (genitive plural in the nominative case). The counted noun is always in genitive singular:
+/// **две третьих метра**, **двум третьим метра**.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{categories::Case, numeral::Fraction};
+///
+/// let n = Fraction::new(2, 3).unwrap();
+/// assert_eq!(n.to_string(), "две третьих");
+/// assert_eq!(n.inflect(Case::Instrumental), "двумя третьими");
+///
+/// let n = Fraction::new(1, 100).unwrap();
+/// assert_eq!(n.inflect(Case::Accusative), "одну сотую");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    numerator: u64,
+    denominator: Ordinal,
+}
+
+impl Fraction {
+    /// Constructs a fractional numeral. Returns `None` if the denominator is zero.
+    #[must_use]
+    pub fn new(numerator: u64, denominator: u64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        Some(Self { numerator, denominator: Ordinal::new(denominator) })
+    }
+
+    /// Returns the fraction's numerator.
+    #[must_use]
+    pub const fn numerator(&self) -> u64 {
+        self.numerator
+    }
+    /// Returns the fraction's denominator.
+    #[must_use]
+    pub const fn denominator(&self) -> u64 {
+        self.denominator.value()
+    }
+
+    /// Returns the words of the numeral in the specified case.
+    #[must_use]
+    pub fn inflect(&self, case: Case) -> String {
+        let numerator = Cardinal::new(self.numerator).inflect(part_info(case, Number::Singular));
+        let denominator = self.denominator.inflect(part_info_after(self.numerator, case));
+        format!("{numerator} {denominator}")
+    }
+}
+
+/// A decimal numeral: **три целых пять десятых**, **ноль целых двадцать пять сотых**.
+///
+/// Decimals are read as a sum of the integer part (with the word **целая**) and a [`Fraction`]
+/// with a denominator of a power of ten. The counted noun is always in genitive singular: **три
+/// целых пять десятых метра**.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{categories::Case, numeral::Decimal};
+///
+/// let n = Decimal::new(35, 1).unwrap(); // 3.5
+/// assert_eq!(n.to_string(), "три целых пять десятых");
+/// assert_eq!(n.inflect(Case::Dative), "трём целым пяти десятым");
+///
+/// let n = Decimal::new(1025, 3).unwrap(); // 1.025
+/// assert_eq!(n.to_string(), "одна целая двадцать пять тысячных");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    integer: u64,
+    fraction: Fraction,
+}
+
+impl Decimal {
+    /// Constructs a decimal numeral equal to `mantissa × 10^-scale`. Returns `None` if the scale
+    /// is zero, or if `10^scale` doesn't fit in [`u64`].
+    #[must_use]
+    pub fn new(mantissa: u64, scale: u32) -> Option<Self> {
+        let denominator = 10u64.checked_pow(scale).filter(|_| scale > 0)?;
+        let fraction = Fraction::new(mantissa % denominator, denominator)?;
+        Some(Self { integer: mantissa / denominator, fraction })
+    }
+
+    /// Returns the decimal's integer part.
+    #[must_use]
+    pub const fn integer(&self) -> u64 {
+        self.integer
+    }
+    /// Returns the decimal's fractional part.
+    #[must_use]
+    pub const fn fraction(&self) -> &Fraction {
+        &self.fraction
+    }
+
+    /// Returns the words of the numeral in the specified case.
+    #[must_use]
+    pub fn inflect(&self, case: Case) -> String {
+        let integer = Cardinal::new(self.integer).inflect(part_info(case, Number::Singular));
+        let whole = WHOLE.inflect(part_info_after(self.integer, case));
+        format!("{integer} {} {}", whole.as_str(), self.fraction.inflect(case))
+    }
+}
+
+/// The numeral **полтора**/**полторы** (one and a half). In the nominative case, the counted
+/// noun is in genitive singular (**полтора часа**), and in oblique cases it agrees with the
+/// numeral in plural (**полутора часов**).
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Case, DeclInfo, Gender},
+///     numeral::OneAndHalf,
+/// };
+///
+/// let info = DeclInfo { gender: Gender::Feminine, ..DeclInfo::default() };
+/// assert_eq!(OneAndHalf.inflect(info), "полторы");
+///
+/// let info = DeclInfo { case: Case::Genitive, ..info };
+/// assert_eq!(OneAndHalf.inflect(info), "полутора");
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Default, Clone, PartialEq)]
+pub struct OneAndHalf;
+
+/// The numeral **полтораста** (one hundred and fifty). In the nominative case, the counted noun
+/// is in genitive plural (**полтораста рублей**), and in oblique cases it agrees with the
+/// numeral in plural (**полутораста рублям**).
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Default, Clone, PartialEq)]
+pub struct HundredAndFifty;
+
+impl OneAndHalf {
+    /// Returns the numeral in the specified case, agreeing with a noun of the specified gender.
+    #[must_use]
+    pub fn inflect(self, info: DeclInfo) -> String {
+        let forms =
+            if info.gender == Gender::Feminine { &ONE_AND_HALF[1] } else { &ONE_AND_HALF[0] };
+        forms[info.case as usize].to_owned()
+    }
+}
+impl HundredAndFifty {
+    /// Returns the numeral in the specified case.
+    #[must_use]
+    pub fn inflect(self, case: Case) -> String {
+        HUNDRED_AND_FIFTY[case as usize].to_owned()
+    }
+}
+
+/// Returns the declension parameters of a fraction's part (**доля**, **целая**).
+const fn part_info(case: Case, number: Number) -> DeclInfo {
+    DeclInfo { case, number, gender: Gender::Feminine, animacy: Animacy::Inanimate }
+}
+/// Returns the declension parameters of a fraction's part, counted by the specified number:
+/// **одна третья**, **две третьих**, **двум третьим**.
+const fn part_info_after(n: u64, case: Case) -> DeclInfo {
+    match (CountForm::of(n), case) {
+        (CountForm::One, _) => part_info(case, Number::Singular),
+        (_, Case::Nominative | Case::Accusative) => part_info(Case::Genitive, Number::Plural),
+        _ => part_info(case, Number::Plural),
+    }
+}
+
+impl CountingNumeral for Fraction {
+    fn inflect(&self, info: DeclInfo) -> String {
+        Self::inflect(self, info.case)
+    }
+    fn count_form(&self) -> CountForm {
+        CountForm::Fraction
+    }
+    fn is_animate(&self) -> bool {
+        false
+    }
+}
+impl CountingNumeral for Decimal {
+    fn inflect(&self, info: DeclInfo) -> String {
+        Self::inflect(self, info.case)
+    }
+    fn count_form(&self) -> CountForm {
+        CountForm::Fraction
+    }
+    fn is_animate(&self) -> bool {
+        false
+    }
+}
+impl CountingNumeral for OneAndHalf {
+    fn inflect(&self, info: DeclInfo) -> String {
+        Self::inflect(*self, info)
+    }
+    fn count_form(&self) -> CountForm {
+        CountForm::Few
+    }
+    fn is_animate(&self) -> bool {
+        false
+    }
+}
+impl CountingNumeral for HundredAndFifty {
+    fn inflect(&self, info: DeclInfo) -> String {
+        Self::inflect(*self, info.case)
+    }
+    fn count_form(&self) -> CountForm {
+        CountForm::Many
+    }
+    fn is_animate(&self) -> bool {
+        false
+    }
+}
+
+impl std::fmt::Display for Fraction {
+    /// Formats the numeral in the nominative case: `две третьих`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inflect(Case::Nominative))
+    }
+}
+impl std::fmt::Display for Decimal {
+    /// Formats the numeral in the nominative case: `три целых пять десятых`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inflect(Case::Nominative))
+    }
+}
+impl std::fmt::Display for OneAndHalf {
+    /// Formats the numeral in the nominative case, masculine gender: `полтора`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inflect(DeclInfo::default()))
+    }
+}
+impl std::fmt::Display for HundredAndFifty {
+    /// Formats the numeral in the nominative case: `полтораста`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&Self::inflect(*self, Case::Nominative))
+    }
+}
+
+/// The adjective **целая** (whole), used between a decimal's integer and fractional parts.
+static WHOLE: LazyLock<Adjective> =
+    LazyLock::new(|| Adjective::from_word("це́лый", "п 1a".parse().unwrap()).unwrap());
+
+#[rustfmt::skip]
+const ONE_AND_HALF: [Forms; 2] = [
+    ["полтора", "полутора", "полутора", "полтора", "полутора", "полутора"],
+    ["полторы", "полутора", "полутора", "полторы", "полутора", "полутора"],
+];
+#[rustfmt::skip]
+const HUNDRED_AND_FIFTY: Forms =
+    ["полтораста", "полутораста", "полутораста", "полтораста", "полутораста", "полутораста"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phrase::phrase;
+
+    #[test]
+    fn whole() {
+        let whole = WHOLE.inflect(DeclInfo::default());
+        assert_eq!(whole.as_str(), "целый");
+    }
+
+    #[test]
+    fn fraction() {
+        let n = Fraction::new(2, 3).unwrap();
+        assert_eq!(n.inflect(Case::Genitive), "двух третьих");
+        assert_eq!(n.inflect(Case::Dative), "двум третьим");
+        assert_eq!(n.inflect(Case::Accusative), "две третьих");
+        let n = Fraction::new(1, 2).unwrap();
+        assert_eq!(n.inflect(Case::Nominative), "одна вторая");
+        assert_eq!(n.inflect(Case::Instrumental), "одной второй");
+        let n = Fraction::new(21, 1000).unwrap();
+        assert_eq!(n.inflect(Case::Prepositional), "двадцати одной тысячной");
+        let n = Fraction::new(5, 8).unwrap();
+        assert_eq!(n.inflect(Case::Nominative), "пять восьмых");
+        assert_eq!(Fraction::new(1, 0), None);
+
+        let x = phrase(&[], ("ме́тр", "м 1a"));
+        let n = Fraction::new(2, 3).unwrap();
+        assert_eq!(x.inflect_counted(&n, Case::Nominative), "две третьих метра");
+        assert_eq!(x.inflect_counted(&n, Case::Dative), "двум третьим метра");
+    }
+
+    #[test]
+    fn decimal() {
+        let n = Decimal::new(35, 1).unwrap();
+        assert_eq!(n.inflect(Case::Genitive), "трёх целых пяти десятых");
+        assert_eq!(n.inflect(Case::Instrumental), "тремя целыми пятью десятыми");
+        let n = Decimal::new(15, 1).unwrap();
+        assert_eq!(n.inflect(Case::Accusative), "одну целую пять десятых");
+        let n = Decimal::new(25, 2).unwrap();
+        assert_eq!(n.to_string(), "ноль целых двадцать пять сотых");
+        let n = Decimal::new(1, 4).unwrap();
+        assert_eq!(n.to_string(), "ноль целых одна десятитысячная");
+        assert_eq!(Decimal::new(1, 0), None);
+        assert_eq!(Decimal::new(1, 20), None);
+
+        let x = phrase(&[], ("ме́тр", "м 1a"));
+        let n = Decimal::new(35, 1).unwrap();
+        assert_eq!(x.inflect_counted(&n, Case::Instrumental), "тремя целыми пятью десятыми метра");
+    }
+
+    #[test]
+    fn one_and_half() {
+        let x = phrase(&[], ("ча́с", "м 1c, Сч"));
+        assert_eq!(x.inflect_counted(&OneAndHalf, Case::Nominative), "полтора часа");
+        assert_eq!(x.inflect_counted_words(&OneAndHalf, Case::Nominative)[0].to_string(), "часа́");
+        assert_eq!(x.inflect_counted(&OneAndHalf, Case::Genitive), "полутора часов");
+        let x = phrase(&[], ("мину́та", "ж 1a"));
+        assert_eq!(x.inflect_counted(&OneAndHalf, Case::Accusative), "полторы минуты");
+        assert_eq!(x.inflect_counted(&OneAndHalf, Case::Dative), "полутора минутам");
+
+        let x = phrase(&[], ("ру́бль", "м 2b"));
+        assert_eq!(x.inflect_counted(&HundredAndFifty, Case::Nominative), "полтораста рублей");
+        assert_eq!(x.inflect_counted(&HundredAndFifty, Case::Dative), "полутораста рублям");
+    }
+}
@@ -0,0 +1,58 @@
+//! Numerals (числительные), spelled out in words.
+//!
+//! Unlike most words in the dictionary, cardinal numerals don't follow any of the standard
+//! declension types, and compound cardinals decline every one of their words: **две тысячи
+//! двадцать один**, **двумя тысячами двадцатью одним**. Ordinal numerals, on the other hand, are
+//! declined as adjectives, and only in their last word: **две тысячи двадцать первого**.
+//!
+//! This module spells both out from integers, declining them according to
+//! [`DeclInfo`], along with collective numerals ([`Collective`], [`Both`]), fractions
+//! ([`Fraction`], [`Decimal`]), and the numerals [`OneAndHalf`] and [`HundredAndFifty`].
+//!
+//! # Counted nouns
+//!
+//! Each kind of numeral governs the noun it counts differently: **два рубля**, **пять рублей**,
+//! **двое суток**, **две третьих метра**. Numerals implementing [`CountingNumeral`] can be
+//! inflected together with the counted noun phrase using
+//! [`NounPhrase::inflect_counted`][crate::phrase::NounPhrase::inflect_counted].
+use crate::{
+    categories::{Animacy, Case, DeclInfo},
+    phrase::CountForm,
+};
+
+mod cardinal;
+mod collective;
+mod fraction;
+mod ordinal;
+
+pub use cardinal::*;
+pub use collective::*;
+pub use fraction::*;
+pub use ordinal::*;
+
+/// A numeral, that counts nouns: cardinal, collective or fractional.
+pub trait CountingNumeral {
+    /// Returns the numeral's words in the specified case, agreeing with a noun of the specified
+    /// gender and animacy.
+    fn inflect(&self, info: DeclInfo) -> String;
+    /// Returns the form the counted noun takes after this numeral.
+    fn count_form(&self) -> CountForm;
+    /// Returns `true` if the numeral itself is declined as animate in the accusative case, with
+    /// the counted noun agreeing with it in plural: вижу двух котов, обоих котов.
+    fn is_animate(&self) -> bool;
+}
+
+// Forms in order of cases: nominative, genitive, dative, accusative (inanimate), instrumental,
+//   and prepositional. Animate accusative forms are the same as genitive ones.
+type Forms = [&'static str; 6];
+
+/// Selects the form for the specified case. Animate accusative uses the genitive form, unless
+/// the accusative form differs from the nominative one (одна --- одну).
+fn select_form(forms: &Forms, info: DeclInfo) -> &'static str {
+    match info.case {
+        Case::Accusative if info.animacy == Animacy::Animate && forms[3] == forms[0] => {
+            forms[Case::Genitive as usize]
+        },
+        case => forms[case as usize],
+    }
+}
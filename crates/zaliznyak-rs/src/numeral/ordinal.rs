@@ -0,0 +1,287 @@
+use crate::{
+    adjective::{Adjective, AdjectiveInfo},
+    categories::{Case, DeclInfo},
+    declension::Declension,
+    numeral::{Cardinal, cardinal::push_triple},
+};
+use std::{str::FromStr, sync::LazyLock};
+
+/// An ordinal numeral (порядковое числительное), spelled out in words.
+///
+/// Ordinals decline just like adjectives, and only the last word of a compound ordinal is
+/// declined: **двадцать третий**, **двадцать третьего**. The preceding words are cardinals in
+/// the nominative case. Ordinals of round thousands, millions, etc. are written in one word:
+/// **двухтысячный**, **стомиллионный**.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Case, DeclInfo},
+///     numeral::Ordinal,
+/// };
+///
+/// let n = Ordinal::new(23);
+/// assert_eq!(n.to_string(), "двадцать третий");
+///
+/// let info = DeclInfo { case: Case::Genitive, ..DeclInfo::default() };
+/// assert_eq!(n.inflect(info), "двадцать третьего"); // (числа) мая
+///
+/// assert_eq!(Ordinal::new(2000).inflect(info), "двухтысячного");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ordinal {
+    value: u64,
+    prefix: String,
+    adjective: Adjective,
+}
+
+impl Ordinal {
+    /// Constructs an ordinal numeral with the specified value.
+    #[must_use]
+    pub fn new(value: u64) -> Self {
+        let (last, adjective) = last_word(value);
+
+        let rest = value - last;
+        let prefix = if rest > 0 { Cardinal::new(rest).to_string() } else { String::new() };
+
+        Self { value, prefix, adjective }
+    }
+
+    /// Returns the numeral's value.
+    #[must_use]
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+    /// Returns the undeclined words preceding the ordinal's last word, or an empty string if the
+    /// ordinal consists of a single word.
+    #[must_use]
+    pub const fn prefix(&self) -> &str {
+        self.prefix.as_str()
+    }
+    /// Returns the ordinal's last word, that is declined as an adjective.
+    #[must_use]
+    pub const fn adjective(&self) -> &Adjective {
+        &self.adjective
+    }
+
+    /// Returns the words of the numeral, with the last word inflected according to the specified
+    /// declension parameters.
+    #[must_use]
+    pub fn inflect(&self, info: DeclInfo) -> String {
+        let word = self.adjective.inflect(info);
+
+        if self.prefix.is_empty() {
+            return word.as_str().to_owned();
+        }
+        format!("{} {}", self.prefix, word.as_str())
+    }
+}
+
+impl std::fmt::Display for Ordinal {
+    /// Formats the numeral in the nominative case, masculine gender: `двадцать первый`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inflect(DeclInfo::default()))
+    }
+}
+
+/// Returns the value of the ordinal's last word, and the word itself.
+fn last_word(value: u64) -> (u64, Adjective) {
+    let tail = value % 1000;
+    let [units, teens, tens, hundreds] = &*ADJECTIVES;
+
+    let (last, adjective) = match (tail % 100, tail % 10) {
+        _ if value == 0 => (0, &units[0]),
+        (10..=19, _) => (tail % 100, &teens[(tail % 10) as usize]),
+        (_, 1..) => (tail % 10, &units[(tail % 10) as usize]),
+        (1.., _) => (tail % 100, &tens[(tail / 10 % 10) as usize - 2]),
+        _ if tail > 0 => (tail, &hundreds[(tail / 100) as usize - 1]),
+        _ => {
+            // Round thousands, millions, etc. are a single compound word: двухтысячный
+            let mut scale = 1000;
+            let mut index = 0;
+            while (value / scale).is_multiple_of(1000) {
+                scale *= 1000;
+                index += 1;
+            }
+            let count = value / scale % 1000;
+            let word = compound_prefix(count) + SCALES[index];
+            return (count * scale, adjective(&word, A));
+        },
+    };
+    (last, adjective.clone())
+}
+
+/// Constructs the adjective of an ordinal's last word.
+fn adjective(word: &str, info: AdjectiveInfo) -> Adjective {
+    match word.strip_suffix('й') {
+        // Третий declines like a pronoun, and its stem can't be identified from the
+        //   nominative form alone.
+        Some(stem) if matches!(info.declension, Some(Declension::Pronoun(_))) => {
+            Adjective::from_stem(stem.parse().unwrap(), info)
+        },
+        _ => Adjective::from_word(word, info).unwrap(),
+    }
+}
+
+/// Returns the first part of a compound ordinal, counting thousands, millions, etc.: **двух**,
+/// **двадцатиодно**, **сорока**, **стапятидесяти**.
+fn compound_prefix(count: u64) -> String {
+    match count {
+        1 => return String::new(),
+        // стотысячный, but стапятидесятитысячный
+        100 => return "сто".to_owned(),
+        _ => {},
+    }
+
+    let mut words = Vec::new();
+    let info = DeclInfo { case: Case::Genitive, ..DeclInfo::default() };
+    push_triple(&mut words, count as u16, info);
+    let prefix = words.concat().replace("девяноста", "девяносто");
+
+    // One is the only numeral, whose compound form differs from its genitive form
+    match prefix.strip_suffix("одного") {
+        Some(prefix) => prefix.to_owned() + "одно",
+        None => prefix,
+    }
+}
+
+const fn parse_info(s: &str) -> AdjectiveInfo {
+    match AdjectiveInfo::from_str(s) {
+        Ok(info) => info,
+        Err(_) => panic!("invalid adjective info"),
+    }
+}
+
+const A: AdjectiveInfo = parse_info("числ.-п <п 1a>");
+const B: AdjectiveInfo = parse_info("числ.-п <п 1b>");
+
+/// The adjectives of all words in the tables below, constructed only once.
+static ADJECTIVES: LazyLock<[Vec<Adjective>; 4]> = LazyLock::new(|| {
+    [&UNITS[..], &TEENS, &TENS, &HUNDREDS]
+        .map(|table| table.iter().map(|&(word, info)| adjective(word, info)).collect())
+});
+
+const UNITS: [(&str, AdjectiveInfo); 10] = [
+    ("нулево́й", B),
+    ("пе́рвый", A),
+    ("второ́й", B),
+    ("тре́тий", parse_info("числ.-п <мс 6*a>")),
+    ("четвёртый", A),
+    ("пя́тый", A),
+    ("шесто́й", B),
+    ("седьмо́й", B),
+    ("восьмо́й", B),
+    ("девя́тый", A),
+];
+const TEENS: [(&str, AdjectiveInfo); 10] = [
+    ("деся́тый", A),
+    ("оди́ннадцатый", A),
+    ("двена́дцатый", A),
+    ("трина́дцатый", A),
+    ("четы́рнадцатый", A),
+    ("пятна́дцатый", A),
+    ("шестна́дцатый", A),
+    ("семна́дцатый", A),
+    ("восемна́дцатый", A),
+    ("девятна́дцатый", A),
+];
+const TENS: [(&str, AdjectiveInfo); 8] = [
+    ("двадца́тый", A),
+    ("тридца́тый", A),
+    ("сороково́й", B),
+    ("пятидеся́тый", A),
+    ("шестидеся́тый", A),
+    ("семидеся́тый", A),
+    ("восьмидеся́тый", A),
+    ("девяно́стый", A),
+];
+const HUNDREDS: [(&str, AdjectiveInfo); 9] = [
+    ("со́тый", A),
+    ("двухсо́тый", A),
+    ("трёхсо́тый", A),
+    ("четырёхсо́тый", A),
+    ("пятисо́тый", A),
+    ("шестисо́тый", A),
+    ("семисо́тый", A),
+    ("восьмисо́тый", A),
+    ("девятисо́тый", A),
+];
+const SCALES: [&str; 6] =
+    ["ты́сячный", "миллио́нный", "миллиа́рдный", "триллио́нный", "квадриллио́нный", "квинтиллио́нный"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adjective::AdjectiveKind,
+        categories::{Animacy, Gender, Number},
+    };
+
+    #[test]
+    fn tables() {
+        let tables = [&UNITS[..], &TEENS, &TENS, &HUNDREDS];
+        for (table, adjectives) in tables.iter().zip(&*ADJECTIVES) {
+            assert_eq!(table.len(), adjectives.len());
+            for (&(word, _), adjective) in table.iter().zip(adjectives) {
+                let nominative = adjective.inflect(DeclInfo::default());
+                assert_eq!(nominative.as_str(), word.replace('\u{301}', ""));
+            }
+        }
+    }
+
+    #[test]
+    fn nominative() {
+        let cases = [
+            (0, "нулевой"),
+            (1, "первый"),
+            (3, "третий"),
+            (10, "десятый"),
+            (13, "тринадцатый"),
+            (23, "двадцать третий"),
+            (40, "сороковой"),
+            (100, "сотый"),
+            (111, "сто одиннадцатый"),
+            (300, "трёхсотый"),
+            (1000, "тысячный"),
+            (1001, "одна тысяча первый"),
+            (2000, "двухтысячный"),
+            (2023, "две тысячи двадцать третий"),
+            (21_000, "двадцатиоднотысячный"),
+            (90_000, "девяностотысячный"),
+            (100_000, "стотысячный"),
+            (150_000, "стапятидесятитысячный"),
+            (1_500_000, "один миллион пятисоттысячный"),
+            (40_000_000, "сорокамиллионный"),
+        ];
+        for (n, s) in cases {
+            assert_eq!(Ordinal::new(n).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn inflect() {
+        let info = |case, gender| DeclInfo {
+            case,
+            number: Number::Singular,
+            gender,
+            animacy: Animacy::Inanimate,
+        };
+
+        let n = Ordinal::new(23);
+        assert_eq!(n.prefix(), "двадцать");
+        assert_eq!(n.adjective().info().kind, AdjectiveKind::Numeral);
+        assert_eq!(n.inflect(info(Case::Genitive, Gender::Neuter)), "двадцать третьего");
+        assert_eq!(n.inflect(info(Case::Accusative, Gender::Feminine)), "двадцать третью");
+        assert_eq!(n.inflect(info(Case::Nominative, Gender::Neuter)), "двадцать третье");
+
+        let n = Ordinal::new(2002);
+        assert_eq!(n.inflect(info(Case::Prepositional, Gender::Masculine)), "две тысячи втором");
+        assert_eq!(n.inflect(info(Case::Instrumental, Gender::Feminine)), "две тысячи второй");
+
+        let n = Ordinal::new(1_000_000);
+        assert_eq!(n.inflect(info(Case::Dative, Gender::Masculine)), "миллионному");
+        let pl = DeclInfo { number: Number::Plural, ..info(Case::Genitive, Gender::Masculine) };
+        assert_eq!(Ordinal::new(90).inflect(pl), "девяностых");
+    }
+}
@@ -0,0 +1,455 @@
+//! Full inflection paradigms of nouns, adjectives and pronouns.
+//!
+//! A paradigm is a table of all forms of a word, that can be indexed by grammatical categories,
+//! or iterated over as `(DeclInfo, WordBuf)` pairs, in the order of Zaliznyak's tables.
+//!
+//! # Examples
+//!
+//! ```
+//! use zaliznyak::{
+//!     categories::{Case, Number},
+//!     noun::Noun,
+//! };
+//!
+//! let noun = Noun::from_word("бое́ц", "мо 5*b".parse().unwrap()).unwrap();
+//! let paradigm = noun.paradigm();
+//!
+//! assert_eq!(paradigm[(Case::Genitive, Number::Plural)].as_str(), "бойцов");
+//!
+//! let forms: Vec<_> = paradigm.iter().map(|(_, word)| word.as_str()).take(3).collect();
+//! assert_eq!(forms, ["боец", "бойца", "бойцу"]);
+//! ```
+//!
+//! Adjective and pronoun paradigms are indexed by [`DeclInfo`], with the accusative case
+//! depending on the animacy:
+//!
+//! ```
+//! use zaliznyak::{
+//!     adjective::Adjective,
+//!     categories::{Animacy, Case, DeclInfo, Gender, Number},
+//! };
+//!
+//! let adj = Adjective::from_word("но́вый", "п 1a".parse().unwrap()).unwrap();
+//! let paradigm = adj.paradigm();
+//!
+//! let mut info = DeclInfo {
+//!     case: Case::Accusative,
+//!     number: Number::Plural,
+//!     gender: Gender::Masculine,
+//!     animacy: Animacy::Inanimate,
+//! };
+//! assert_eq!(paradigm[info].as_str(), "новые");
+//! info.animacy = Animacy::Animate;
+//! assert_eq!(paradigm[info].as_str(), "новых");
+//!
+//! assert_eq!(paradigm.short(Number::Singular, Gender::Feminine).unwrap().as_str(), "нова");
+//! assert_eq!(paradigm.comparative().unwrap().as_str(), "новее");
+//! ```
+use crate::{
+    adjective::Adjective,
+    categories::{Animacy, Case, CaseEx, DeclInfo, Gender, GenderEx, Number},
+    declension::{InflectError, unwrap_inflected},
+    noun::Noun,
+    pronoun::Pronoun,
+    word::WordBuf,
+};
+
+/// A paradigm of a noun: the 6 primary cases in both numbers (or only one of the numbers, for
+/// pluralia and singularia tantum), and distinct partitive and locative forms, if the noun has
+/// them (see [`NounFlags`][crate::noun::NounFlags]).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NounParadigm {
+    tantum: Option<Number>,
+    forms: Vec<(DeclInfo, WordBuf)>,
+    partitive: Option<WordBuf>,
+    locative: Option<WordBuf>,
+}
+
+/// A paradigm of a word agreeing in gender: the 6 primary cases in masculine, neuter and feminine
+/// singular, and in plural. The accusative case is listed twice, for inanimate and animate nouns.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PronounParadigm {
+    forms: Vec<(DeclInfo, WordBuf)>,
+}
+
+/// A paradigm of an adjective: its full forms (see [`PronounParadigm`]), short forms in
+/// masculine, neuter and feminine singular, and in plural, and the comparative form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AdjectiveParadigm {
+    full: PronounParadigm,
+    short: [Option<WordBuf>; 4],
+    comparative: Option<WordBuf>,
+}
+
+// The columns of gendered paradigms: masculine, neuter, feminine singular and plural
+const COLUMNS: [(Number, Gender); 4] = [
+    (Number::Singular, Gender::Masculine),
+    (Number::Singular, Gender::Neuter),
+    (Number::Singular, Gender::Feminine),
+    (Number::Plural, Gender::Masculine),
+];
+// The rows of gendered paradigms: all cases, with the accusative case split by animacy
+const ROWS: [(Case, Animacy); 7] = [
+    (Case::Nominative, Animacy::Inanimate),
+    (Case::Genitive, Animacy::Inanimate),
+    (Case::Dative, Animacy::Inanimate),
+    (Case::Accusative, Animacy::Inanimate),
+    (Case::Accusative, Animacy::Animate),
+    (Case::Instrumental, Animacy::Inanimate),
+    (Case::Prepositional, Animacy::Inanimate),
+];
+
+const fn column(number: Number, gender: Gender) -> usize {
+    match number {
+        Number::Singular => gender as usize,
+        Number::Plural => 3,
+    }
+}
+
+impl Noun {
+    /// Returns the noun's paradigm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the noun's stem doesn't fit its declension. See [`Noun::try_paradigm`].
+    #[must_use]
+    pub fn paradigm(&self) -> NounParadigm {
+        unwrap_inflected(self.try_paradigm())
+    }
+    /// Returns the noun's paradigm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the noun's forms couldn't be inflected.
+    pub fn try_paradigm(&self) -> Result<NounParadigm, InflectError> {
+        let info = self.info();
+        let numbers: &[Number] = match info.tantum {
+            Some(Number::Singular) => &[Number::Singular],
+            Some(Number::Plural) => &[Number::Plural],
+            None => &Number::VALUES,
+        };
+        // Forms are tagged with the gender the noun agrees in, not the one it declines by
+        let gender = match info.gender {
+            GenderEx::Common => info.declension_gender,
+            gender => gender.normalize(),
+        };
+        let animacy = info.animacy;
+
+        let mut forms = Vec::with_capacity(numbers.len() * Case::VALUES.len());
+        for &number in numbers {
+            for case in Case::VALUES {
+                let word = self.try_inflect(case.into(), number)?;
+                forms.push((DeclInfo { case, number, gender, animacy }, word));
+            }
+        }
+
+        // Secondary cases are only distinct in singular
+        let has_singular = info.tantum != Some(Number::Plural);
+        let partitive = (has_singular && info.flags.has_partitive())
+            .then(|| self.try_inflect(CaseEx::Partitive, Number::Singular))
+            .transpose()?;
+        let locative = (has_singular && info.flags.has_locative())
+            .then(|| self.try_inflect(CaseEx::Locative, Number::Singular))
+            .transpose()?;
+
+        Ok(NounParadigm { tantum: info.tantum, forms, partitive, locative })
+    }
+}
+
+impl NounParadigm {
+    /// Returns the form in the specified case and number, or `None` if the noun doesn't have
+    /// forms in that number.
+    #[must_use]
+    pub fn get(&self, case: Case, number: Number) -> Option<&WordBuf> {
+        let row = match self.tantum {
+            Some(tantum) if tantum != number => return None,
+            Some(_) => 0,
+            None => number as usize,
+        };
+        Some(&self.forms[row * Case::VALUES.len() + case as usize].1)
+    }
+    /// Returns the distinct partitive singular form (ча́ю), if the noun has the Р2 mark.
+    #[must_use]
+    pub fn partitive(&self) -> Option<&WordBuf> {
+        self.partitive.as_ref()
+    }
+    /// Returns the distinct locative singular form (лесу́), if the noun has the П2 mark.
+    #[must_use]
+    pub fn locative(&self) -> Option<&WordBuf> {
+        self.locative.as_ref()
+    }
+
+    /// Returns an iterator over the forms in the primary cases, singular forms first.
+    pub fn iter(&self) -> impl Iterator<Item = (DeclInfo, &WordBuf)> {
+        self.forms.iter().map(|(info, word)| (*info, word))
+    }
+}
+
+impl PronounParadigm {
+    fn try_new(
+        mut inflect: impl FnMut(DeclInfo) -> Result<WordBuf, InflectError>,
+    ) -> Result<Self, InflectError> {
+        let mut forms = Vec::with_capacity(COLUMNS.len() * ROWS.len());
+        for (number, gender) in COLUMNS {
+            for (case, animacy) in ROWS {
+                let info = DeclInfo { case, number, gender, animacy };
+                forms.push((info, inflect(info)?));
+            }
+        }
+        Ok(Self { forms })
+    }
+
+    /// Returns the form with the specified declension parameters. The animacy is only considered
+    /// in the accusative case, and the gender - only in singular.
+    #[must_use]
+    pub fn get(&self, info: DeclInfo) -> &WordBuf {
+        let row = match info.case {
+            Case::Nominative | Case::Genitive | Case::Dative => info.case as usize,
+            Case::Accusative => 3 + info.animacy as usize,
+            Case::Instrumental | Case::Prepositional => info.case as usize + 1,
+        };
+        &self.forms[column(info.number, info.gender) * ROWS.len() + row].1
+    }
+
+    /// Returns an iterator over the forms, column by column: masculine, neuter and feminine
+    /// singular, and then plural.
+    pub fn iter(&self) -> impl Iterator<Item = (DeclInfo, &WordBuf)> {
+        self.forms.iter().map(|(info, word)| (*info, word))
+    }
+}
+
+impl Pronoun {
+    /// Returns the pronoun's paradigm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pronoun's stem doesn't fit its declension. See [`Pronoun::try_paradigm`].
+    #[must_use]
+    pub fn paradigm(&self) -> PronounParadigm {
+        unwrap_inflected(self.try_paradigm())
+    }
+    /// Returns the pronoun's paradigm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the pronoun's forms couldn't be inflected.
+    pub fn try_paradigm(&self) -> Result<PronounParadigm, InflectError> {
+        PronounParadigm::try_new(|info| self.try_inflect(info))
+    }
+}
+
+impl Adjective {
+    /// Returns the adjective's paradigm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the adjective's stem doesn't fit its declension. See
+    /// [`Adjective::try_paradigm`].
+    #[must_use]
+    pub fn paradigm(&self) -> AdjectiveParadigm {
+        unwrap_inflected(self.try_paradigm())
+    }
+    /// Returns the adjective's paradigm. Short forms marked as difficult to form (see
+    /// [`AdjectiveFlags`][crate::adjective::AdjectiveFlags]) are not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the adjective's forms couldn't be inflected.
+    pub fn try_paradigm(&self) -> Result<AdjectiveParadigm, InflectError> {
+        let full = PronounParadigm::try_new(|info| self.try_inflect(info))?;
+
+        let mut short = [const { None }; 4];
+        for (form, (number, gender)) in short.iter_mut().zip(COLUMNS) {
+            let info =
+                DeclInfo { case: Case::Nominative, number, gender, animacy: Animacy::Inanimate };
+            *form = self.try_inflect_short(info, false)?;
+        }
+
+        Ok(AdjectiveParadigm { full, short, comparative: self.inflect_comparative() })
+    }
+}
+
+impl AdjectiveParadigm {
+    /// Returns the full form with the specified declension parameters.
+    /// See [`PronounParadigm::get`].
+    #[must_use]
+    pub fn get(&self, info: DeclInfo) -> &WordBuf {
+        self.full.get(info)
+    }
+    /// Returns the paradigm of the adjective's full forms.
+    #[must_use]
+    pub const fn full(&self) -> &PronounParadigm {
+        &self.full
+    }
+    /// Returns the short form in the specified number and gender, or `None` if the adjective
+    /// doesn't have one. The gender is only considered in singular.
+    #[must_use]
+    pub fn short(&self, number: Number, gender: Gender) -> Option<&WordBuf> {
+        self.short[column(number, gender)].as_ref()
+    }
+    /// Returns the comparative form, or `None` if the adjective doesn't have one.
+    #[must_use]
+    pub fn comparative(&self) -> Option<&WordBuf> {
+        self.comparative.as_ref()
+    }
+
+    /// Returns an iterator over the full forms. See [`PronounParadigm::iter`].
+    pub fn iter(&self) -> impl Iterator<Item = (DeclInfo, &WordBuf)> {
+        self.full.iter()
+    }
+    /// Returns an iterator over the existing short forms (in the nominative case).
+    pub fn iter_short(&self) -> impl Iterator<Item = (DeclInfo, &WordBuf)> {
+        let forms = COLUMNS.iter().zip(&self.short);
+        forms.filter_map(|(&(number, gender), word)| {
+            let info =
+                DeclInfo { case: Case::Nominative, number, gender, animacy: Animacy::Inanimate };
+            Some((info, word.as_ref()?))
+        })
+    }
+}
+
+impl std::ops::Index<(Case, Number)> for NounParadigm {
+    type Output = WordBuf;
+    fn index(&self, (case, number): (Case, Number)) -> &Self::Output {
+        self.get(case, number).expect("the noun doesn't have forms in this number")
+    }
+}
+impl std::ops::Index<DeclInfo> for PronounParadigm {
+    type Output = WordBuf;
+    fn index(&self, info: DeclInfo) -> &Self::Output {
+        self.get(info)
+    }
+}
+impl std::ops::Index<DeclInfo> for AdjectiveParadigm {
+    type Output = WordBuf;
+    fn index(&self, info: DeclInfo) -> &Self::Output {
+        self.get(info)
+    }
+}
+
+impl IntoIterator for NounParadigm {
+    type Item = (DeclInfo, WordBuf);
+    type IntoIter = std::vec::IntoIter<(DeclInfo, WordBuf)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.forms.into_iter()
+    }
+}
+impl IntoIterator for PronounParadigm {
+    type Item = (DeclInfo, WordBuf);
+    type IntoIter = std::vec::IntoIter<(DeclInfo, WordBuf)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.forms.into_iter()
+    }
+}
+/// Yields only the full forms, like [`AdjectiveParadigm::iter`]. The short and comparative forms
+/// are dropped, so take them with [`AdjectiveParadigm::short`] and
+/// [`AdjectiveParadigm::comparative`] first.
+impl IntoIterator for AdjectiveParadigm {
+    type Item = (DeclInfo, WordBuf);
+    type IntoIter = std::vec::IntoIter<(DeclInfo, WordBuf)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.full.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn join<'a>(forms: impl Iterator<Item = (DeclInfo, &'a WordBuf)>) -> String {
+        forms.map(|(_, x)| x.as_str()).collect::<Vec<_>>().join(", ")
+    }
+
+    #[test]
+    fn noun() {
+        let noun = Noun::from_word("лёд", "м 1*b, Р2, П2 (на)".parse().unwrap()).unwrap();
+        let paradigm = noun.paradigm();
+
+        assert_eq!(
+            join(paradigm.iter()),
+            "лёд, льда, льду, лёд, льдом, льде, льды, льдов, льдам, льды, льдами, льдах",
+        );
+        assert_eq!(paradigm[(Case::Instrumental, Number::Plural)].as_str(), "льдами");
+        assert_eq!(paradigm.partitive().unwrap().as_str(), "льду");
+        assert_eq!(paradigm.locative().unwrap().as_str(), "льду");
+
+        // Pluralia tantum only have plural forms
+        let noun = Noun::from_word("но́жницы", "мн. <ж 5a>".parse().unwrap()).unwrap();
+        let paradigm = noun.paradigm();
+        assert_eq!(paradigm.iter().count(), 6);
+        assert_eq!(paradigm.get(Case::Genitive, Number::Singular), None);
+        assert_eq!(paradigm[(Case::Genitive, Number::Plural)].as_str(), "ножниц");
+        assert_eq!(paradigm.partitive(), None);
+
+        let forms: Vec<_> = paradigm.into_iter().map(|(info, _)| info.number).collect();
+        assert_eq!(forms, [Number::Plural; 6]);
+
+        // Forms are tagged with the agreement gender, not the declension gender
+        let noun = Noun::from_word("мужчи́на", "мо <жо 1a>".parse().unwrap()).unwrap();
+        let paradigm = noun.paradigm();
+        assert_eq!(paradigm[(Case::Dative, Number::Singular)].as_str(), "мужчине");
+        assert!(paradigm.iter().all(|(info, _)| info.gender == Gender::Masculine));
+    }
+
+    #[test]
+    fn adjective() {
+        let adj = Adjective::from_word("кра́сный", "п 1*a/c′".parse().unwrap()).unwrap();
+        let paradigm = adj.paradigm();
+
+        let column = |number, gender| {
+            join(paradigm.iter().filter(|(x, _)| x.number == number && x.gender == gender))
+        };
+        assert_eq!(
+            column(Number::Singular, Gender::Masculine),
+            "красный, красного, красному, красный, красного, красным, красном",
+        );
+        assert_eq!(
+            column(Number::Singular, Gender::Feminine),
+            "красная, красной, красной, красную, красную, красной, красной",
+        );
+        assert_eq!(
+            column(Number::Plural, Gender::Masculine),
+            "красные, красных, красным, красные, красных, красными, красных",
+        );
+        assert_eq!(paradigm.iter().count(), 28);
+
+        assert_eq!(join(paradigm.iter_short()), "красен, красно, красна, красны");
+        assert_eq!(paradigm.short(Number::Plural, Gender::Feminine).unwrap().as_str(), "красны");
+        assert_eq!(paradigm.comparative().unwrap().as_str(), "краснее");
+    }
+
+    #[test]
+    fn pronoun() {
+        let pronoun = Pronoun::from_word("мо́й", "мс 6b".parse().unwrap()).unwrap();
+        let paradigm = pronoun.paradigm();
+
+        let info = DeclInfo {
+            case: Case::Dative,
+            number: Number::Singular,
+            gender: Gender::Feminine,
+            animacy: Animacy::Inanimate,
+        };
+        assert_eq!(paradigm[info].as_str(), "моей");
+
+        let forms: Vec<_> = paradigm.into_iter().take(8).collect();
+        assert_eq!(forms[4].0.animacy, Animacy::Animate);
+        assert_eq!(forms[7].0.gender, Gender::Neuter);
+        assert_eq!(forms.iter().map(|x| x.1.as_str()).collect::<Vec<_>>(), [
+            "мой",
+            "моего",
+            "моему",
+            "мой",
+            "моего",
+            "моим",
+            "моём",
+            "моё"
+        ],);
+
+        // Pronominal adjectives don't have short and comparative forms
+        let adj = Adjective::from_word("мо́й", "мс-п 6b".parse().unwrap()).unwrap();
+        let paradigm = adj.paradigm();
+        assert_eq!(paradigm[info].as_str(), "моей");
+        assert_eq!(paradigm.iter_short().count(), 0);
+        assert_eq!(paradigm.comparative(), None);
+    }
+}
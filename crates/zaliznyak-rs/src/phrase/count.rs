@@ -0,0 +1,337 @@
+use crate::{
+    categories::{Animacy, Case, CaseEx, DeclInfo, Gender, Number},
+    declension::{Declension, InflectError, unwrap_inflected},
+    numeral::{Cardinal, CountingNumeral},
+    phrase::NounPhrase,
+    stress::NounStress,
+    word::WordBuf,
+};
+
+/// The form a noun takes after a cardinal number, determined by the number's last digits.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::phrase::CountForm;
+///
+/// assert_eq!(CountForm::of(1), CountForm::One); // 1 рубль
+/// assert_eq!(CountForm::of(22), CountForm::Few); // 22 рубля
+/// assert_eq!(CountForm::of(12), CountForm::Many); // 12 рублей
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum CountForm {
+    /// Numbers ending in 1, except for 11: the noun agrees with the number in singular.
+    One,
+    /// Numbers ending in 2, 3 or 4, except for 12, 13 and 14: in the nominative case, the noun
+    /// takes the genitive singular form (or the count form, see
+    /// [`NounFlags::COUNT_FORM`][crate::noun::NounFlags::COUNT_FORM]).
+    Few,
+    /// All other numbers: in the nominative case, the noun takes the genitive plural form.
+    Many,
+    /// Fractions and decimals: the noun takes the genitive singular form in all cases (две
+    /// третьих метра, двум третьим метра). Never returned by [`CountForm::of`].
+    Fraction,
+}
+
+impl CountForm {
+    /// Determines the form a noun takes after the specified number.
+    #[must_use]
+    pub const fn of(n: u64) -> Self {
+        match (n % 10, n % 100) {
+            (_, 11..=14) => Self::Many,
+            (1, _) => Self::One,
+            (2..=4, _) => Self::Few,
+            _ => Self::Many,
+        }
+    }
+}
+
+impl NounPhrase {
+    /// Inflects the phrase's words to agree with the specified number in the specified case:
+    /// the adjectives first, followed by the noun. The number itself is not included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the words' stems doesn't fit its declension.
+    /// See [`NounPhrase::try_inflect_count_words`].
+    #[must_use]
+    pub fn inflect_count_words(&self, n: u64, case: Case) -> Vec<WordBuf> {
+        unwrap_inflected(self.try_inflect_count_words(n, case))
+    }
+    /// Inflects the phrase's words to agree with the specified number in the specified case:
+    /// the adjectives first, followed by the noun. The number itself is not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the words couldn't be inflected.
+    pub fn try_inflect_count_words(
+        &self,
+        n: u64,
+        case: Case,
+    ) -> Result<Vec<WordBuf>, InflectError> {
+        self.try_inflect_counted_words(&Cardinal::new(n), case)
+    }
+
+    /// Inflects the phrase's words to agree with the specified numeral in the specified case:
+    /// the adjectives first, followed by the noun. The numeral itself is not included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the words' stems doesn't fit its declension.
+    /// See [`NounPhrase::try_inflect_counted_words`].
+    #[must_use]
+    pub fn inflect_counted_words(
+        &self,
+        numeral: &impl CountingNumeral,
+        case: Case,
+    ) -> Vec<WordBuf> {
+        unwrap_inflected(self.try_inflect_counted_words(numeral, case))
+    }
+    /// Inflects the phrase's words to agree with the specified numeral in the specified case:
+    /// the adjectives first, followed by the noun. The numeral itself is not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the words couldn't be inflected.
+    pub fn try_inflect_counted_words(
+        &self,
+        numeral: &impl CountingNumeral,
+        case: Case,
+    ) -> Result<Vec<WordBuf>, InflectError> {
+        let noun_info = self.noun.info();
+        let DeclInfo { gender, animacy, .. } = self.agreement(case.into(), Number::Plural);
+
+        let form = numeral.count_form();
+        let (case, number, adj_case, adj_number) = match (form, case) {
+            // 1 рубль, 21 рубля, 101 рублю: agrees in singular
+            (CountForm::One, _) => (case, Number::Singular, case, Number::Singular),
+            // 2/3 метра, 2/3 метру: genitive singular in all cases
+            (CountForm::Fraction, _) => {
+                (Case::Genitive, Number::Singular, Case::Genitive, Number::Singular)
+            },
+            // 2 рублей, 5 рублям: agrees in plural in oblique cases
+            (_, Case::Genitive | Case::Dative | Case::Instrumental | Case::Prepositional) => {
+                (case, Number::Plural, case, Number::Plural)
+            },
+            // Numerals 2, 3 and 4 themselves are declined as animate in the accusative case,
+            //   unlike compound numerals ending in them: вижу двух котов, but вижу 22 кота.
+            (CountForm::Few, Case::Accusative)
+                if animacy == Animacy::Animate && numeral.is_animate() =>
+            {
+                (case, Number::Plural, case, Number::Plural)
+            },
+            // 2 рубля: genitive singular, with adjectives in genitive plural
+            //   (or nominative plural, for feminine nouns: две новые книги).
+            (CountForm::Few, _) => {
+                let adj_case = if gender == Gender::Feminine { case } else { Case::Genitive };
+                (Case::Genitive, Number::Singular, adj_case, Number::Plural)
+            },
+            // 5 рублей: genitive plural
+            (CountForm::Many, _) => {
+                (Case::Genitive, Number::Plural, Case::Genitive, Number::Plural)
+            },
+        };
+        // Adjectives of pluralia tantum are always plural
+        let adj_number = noun_info.tantum.filter(|&x| x == Number::Plural).unwrap_or(adj_number);
+        let adj_info = DeclInfo { case: adj_case, number: adj_number, gender, animacy };
+
+        let mut words = Vec::with_capacity(self.adjectives.len() + 1);
+        for adj in &self.adjectives {
+            words.push(adj.try_inflect(adj_info)?);
+        }
+
+        // The count form (два часа́) differs from genitive singular (ча́са) only by stress,
+        //   that falls on the ending, just like in the locative form.
+        let mut info = noun_info;
+        if form == CountForm::Few
+            && number == Number::Singular
+            && info.flags.has_count_form()
+            && let Some(Declension::Noun(decl)) = &mut info.declension
+        {
+            decl.stress = NounStress::B;
+        }
+        words.push(info.try_inflect(self.noun.stem(), CaseEx::from(case), number)?);
+        Ok(words)
+    }
+
+    /// Inflects the phrase to agree with the specified number in the specified case, and
+    /// prepends the number in digits: `2 рубля`, `5 рублей`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the words' stems doesn't fit its declension.
+    /// See [`NounPhrase::try_inflect_count`].
+    #[must_use]
+    pub fn inflect_count(&self, n: u64, case: Case) -> String {
+        unwrap_inflected(self.try_inflect_count(n, case))
+    }
+    /// Inflects the phrase to agree with the specified number in the specified case, and
+    /// prepends the number in digits: `2 рубля`, `5 рублей`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the words couldn't be inflected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{categories::Case, noun::Noun, phrase::NounPhrase};
+    ///
+    /// let noun = Noun::from_word("ру́бль", "м 2b".parse().unwrap()).unwrap();
+    /// let phrase = NounPhrase::new(noun);
+    ///
+    /// assert_eq!(phrase.try_inflect_count(1, Case::Nominative).unwrap(), "1 рубль");
+    /// assert_eq!(phrase.try_inflect_count(3, Case::Nominative).unwrap(), "3 рубля");
+    /// assert_eq!(phrase.try_inflect_count(25, Case::Nominative).unwrap(), "25 рублей");
+    /// assert_eq!(phrase.try_inflect_count(25, Case::Dative).unwrap(), "25 рублям");
+    /// ```
+    pub fn try_inflect_count(&self, n: u64, case: Case) -> Result<String, InflectError> {
+        let mut s = n.to_string();
+        for word in self.try_inflect_count_words(n, case)? {
+            s.push(' ');
+            s.push_str(word.as_str());
+        }
+        Ok(s)
+    }
+
+    /// Inflects the phrase together with the specified numeral spelled out in words, in the
+    /// specified case: `двое новых студентов`, `двум третьим метра`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the words' stems doesn't fit its declension.
+    /// See [`NounPhrase::try_inflect_counted`].
+    #[must_use]
+    pub fn inflect_counted(&self, numeral: &impl CountingNumeral, case: Case) -> String {
+        unwrap_inflected(self.try_inflect_counted(numeral, case))
+    }
+    /// Inflects the phrase together with the specified numeral spelled out in words, in the
+    /// specified case: `двое новых студентов`, `двум третьим метра`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the words couldn't be inflected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{
+    ///     categories::Case,
+    ///     noun::Noun,
+    ///     numeral::{Cardinal, Collective},
+    ///     phrase::NounPhrase,
+    /// };
+    ///
+    /// let noun = Noun::from_word("ру́бль", "м 2b".parse().unwrap()).unwrap();
+    /// let phrase = NounPhrase::new(noun);
+    /// let n = Cardinal::new(2021);
+    /// let s = phrase.try_inflect_counted(&n, Case::Nominative).unwrap();
+    /// assert_eq!(s, "две тысячи двадцать один рубль");
+    ///
+    /// let noun = Noun::from_word("студе́нт", "мо 1a".parse().unwrap()).unwrap();
+    /// let phrase = NounPhrase::new(noun);
+    /// let n = Collective::new(3).unwrap();
+    /// assert_eq!(phrase.try_inflect_counted(&n, Case::Accusative).unwrap(), "троих студентов");
+    /// ```
+    pub fn try_inflect_counted(
+        &self,
+        numeral: &impl CountingNumeral,
+        case: Case,
+    ) -> Result<String, InflectError> {
+        // Numerals agree in singular, except for pluralia tantum (одни ножницы)
+        let info = self.agreement(case.into(), Number::Singular);
+
+        let mut s = numeral.inflect(info);
+        for word in self.try_inflect_counted_words(numeral, case)? {
+            s.push(' ');
+            s.push_str(word.as_str());
+        }
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phrase::phrase;
+
+    #[test]
+    fn count_form() {
+        let forms = [
+            (0, CountForm::Many),
+            (1, CountForm::One),
+            (2, CountForm::Few),
+            (4, CountForm::Few),
+            (5, CountForm::Many),
+            (11, CountForm::Many),
+            (12, CountForm::Many),
+            (14, CountForm::Many),
+            (21, CountForm::One),
+            (22, CountForm::Few),
+            (111, CountForm::Many),
+            (1000, CountForm::Many),
+            (1001, CountForm::One),
+        ];
+        for (n, form) in forms {
+            assert_eq!(CountForm::of(n), form, "{n}");
+        }
+    }
+
+    #[test]
+    fn nouns() {
+        let x = phrase(&[], ("ру́бль", "м 2b"));
+        assert_eq!(x.inflect_count(1, Case::Nominative), "1 рубль");
+        assert_eq!(x.inflect_count(2, Case::Nominative), "2 рубля");
+        assert_eq!(x.inflect_count(5, Case::Nominative), "5 рублей");
+        assert_eq!(x.inflect_count(11, Case::Nominative), "11 рублей");
+        assert_eq!(x.inflect_count(21, Case::Nominative), "21 рубль");
+        assert_eq!(x.inflect_count(22, Case::Accusative), "22 рубля");
+        assert_eq!(x.inflect_count(21, Case::Genitive), "21 рубля");
+        assert_eq!(x.inflect_count(2, Case::Instrumental), "2 рублями");
+        assert_eq!(x.inflect_count(5, Case::Prepositional), "5 рублях");
+
+        // Animate accusative
+        let x = phrase(&[], ("ко́т", "мо 1b"));
+        assert_eq!(x.inflect_count(1, Case::Accusative), "1 кота");
+        assert_eq!(x.inflect_count(2, Case::Accusative), "2 котов");
+        assert_eq!(x.inflect_count(22, Case::Accusative), "22 кота");
+        assert_eq!(x.inflect_count(5, Case::Accusative), "5 котов");
+
+        // Pluralia tantum
+        let x = phrase(&[("о́стрый", "п 1a")], ("но́жницы", "мн. <ж 5a>"));
+        assert_eq!(x.inflect_count(5, Case::Nominative), "5 острых ножниц");
+        assert_eq!(x.inflect_count(5, Case::Dative), "5 острым ножницам");
+    }
+
+    #[test]
+    fn count_forms() {
+        let x = phrase(&[], ("ча́с", "м 1c, Сч"));
+        let words = x.inflect_count_words(2, Case::Nominative);
+        assert_eq!(words[0].to_string(), "часа́");
+        let words = x.inflect_count_words(5, Case::Nominative);
+        assert_eq!(words[0].to_string(), "часо́в");
+        // Genitive singular is stressed on the stem
+        let words = x.inflect_count_words(1, Case::Genitive);
+        assert_eq!(words[0].to_string(), "ча́са");
+
+        // Nouns without the mark use the plain genitive singular
+        let x = phrase(&[], ("ча́с", "м 1c"));
+        assert_eq!(x.inflect_count_words(2, Case::Nominative)[0].to_string(), "ча́са");
+    }
+
+    #[test]
+    fn adjectives() {
+        let x = phrase(&[("но́вый", "п 1a")], ("сто́л", "м 1b"));
+        assert_eq!(x.inflect_count(1, Case::Nominative), "1 новый стол");
+        assert_eq!(x.inflect_count(2, Case::Nominative), "2 новых стола");
+        assert_eq!(x.inflect_count(5, Case::Nominative), "5 новых столов");
+        assert_eq!(x.inflect_count(3, Case::Dative), "3 новым столам");
+
+        let x = phrase(&[("но́вый", "п 1a")], ("кни́га", "ж 3a"));
+        assert_eq!(x.inflect_count(1, Case::Accusative), "1 новую книгу");
+        assert_eq!(x.inflect_count(2, Case::Nominative), "2 новые книги");
+        assert_eq!(x.inflect_count(5, Case::Nominative), "5 новых книг");
+        assert_eq!(x.inflect_count(22, Case::Instrumental), "22 новыми книгами");
+    }
+}
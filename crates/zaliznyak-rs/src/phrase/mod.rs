@@ -0,0 +1,228 @@
+//! Noun phrases: nouns with attached adjectives, inflected together.
+//!
+//! Adjectives agree with the noun in case, number, gender and animacy. The gender used is the
+//! noun's agreement gender, which may differ from the gender of its endings: "мужчина" (man)
+//! declines with feminine endings, but the adjectives referring to it are masculine.
+//!
+//! # Examples
+//!
+//! ```
+//! use zaliznyak::{
+//!     adjective::Adjective,
+//!     categories::{CaseEx, Number},
+//!     noun::Noun,
+//!     phrase::NounPhrase,
+//! };
+//!
+//! let noun = Noun::from_word("мужчи́на", "мо <жо 1a>".parse().unwrap()).unwrap();
+//! let adj = Adjective::from_word("краси́вый", "п 1a".parse().unwrap()).unwrap();
+//! let phrase = NounPhrase { adjectives: vec![adj], ..NounPhrase::new(noun) };
+//!
+//! assert_eq!(phrase.inflect(CaseEx::Dative, Number::Singular), "красивому мужчине");
+//! assert_eq!(phrase.inflect(CaseEx::Accusative, Number::Plural), "красивых мужчин");
+//! ```
+//!
+//! Nouns of [common gender][GenderEx::Common] (сирота, умница) agree with the gender of the
+//! person they refer to, which can be specified explicitly:
+//!
+//! ```
+//! use zaliznyak::{
+//!     adjective::Adjective,
+//!     categories::{CaseEx, Gender, Number},
+//!     noun::Noun,
+//!     phrase::NounPhrase,
+//! };
+//!
+//! let noun = Noun::from_word("сирота́", "мо-жо 1d".parse().unwrap()).unwrap();
+//! let adj = Adjective::from_word("кру́глый", "п 1a".parse().unwrap()).unwrap();
+//!
+//! let mut phrase = NounPhrase { adjectives: vec![adj], ..NounPhrase::new(noun) };
+//! assert_eq!(phrase.inflect(CaseEx::Genitive, Number::Singular), "круглой сироты");
+//!
+//! phrase.referent = Some(Gender::Masculine);
+//! assert_eq!(phrase.inflect(CaseEx::Genitive, Number::Singular), "круглого сироты");
+//! ```
+use crate::{
+    adjective::Adjective,
+    categories::{CaseEx, DeclInfo, Gender, GenderEx, Number},
+    declension::{InflectError, unwrap_inflected},
+    noun::Noun,
+    word::WordBuf,
+};
+
+mod count;
+
+pub use count::*;
+
+/// A noun with attached adjectives, that agree with it. See the [module-level
+/// documentation][self] for more details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NounPhrase {
+    /// The adjectives attached to the noun, in the order they precede it.
+    pub adjectives: Vec<Adjective>,
+    /// The head noun of the phrase.
+    pub noun: Noun,
+    /// The gender of the person the noun refers to. Only used by nouns of common gender, which
+    /// otherwise agree in the gender of their endings
+    /// ([`declension_gender`][crate::noun::NounInfo::declension_gender]): feminine for nouns in
+    /// **-а**/**-я**, such as **сирота**.
+    pub referent: Option<Gender>,
+}
+
+impl NounPhrase {
+    /// Constructs a noun phrase without any adjectives.
+    #[must_use]
+    pub const fn new(noun: Noun) -> Self {
+        Self { adjectives: Vec::new(), noun, referent: None }
+    }
+
+    /// Returns the declension parameters, that words agreeing with the noun in the specified
+    /// case and number must be inflected with.
+    #[must_use]
+    pub fn agreement(&self, case: CaseEx, number: Number) -> DeclInfo {
+        let info = self.noun.info();
+
+        let gender = match info.gender {
+            GenderEx::Common => self.referent.unwrap_or(info.declension_gender),
+            gender => gender.normalize(),
+        };
+        // Pluralia and singularia tantum agree in their only number
+        let (case, number) = case.normalize_with(info.tantum.unwrap_or(number));
+
+        DeclInfo { case, number, gender, animacy: info.animacy }
+    }
+
+    /// Inflects the phrase's words into the specified case and number: the adjectives first,
+    /// followed by the noun.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the words' stems doesn't fit its declension.
+    /// See [`NounPhrase::try_inflect_words`].
+    #[must_use]
+    pub fn inflect_words(&self, case: CaseEx, number: Number) -> Vec<WordBuf> {
+        unwrap_inflected(self.try_inflect_words(case, number))
+    }
+    /// Inflects the phrase's words into the specified case and number: the adjectives first,
+    /// followed by the noun.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the words couldn't be inflected.
+    pub fn try_inflect_words(
+        &self,
+        case: CaseEx,
+        number: Number,
+    ) -> Result<Vec<WordBuf>, InflectError> {
+        let info = self.agreement(case, number);
+
+        let mut words = Vec::with_capacity(self.adjectives.len() + 1);
+        for adj in &self.adjectives {
+            words.push(adj.try_inflect(info)?);
+        }
+        words.push(self.noun.try_inflect(case, number)?);
+        Ok(words)
+    }
+
+    /// Inflects the phrase into the specified case and number, joining the words with spaces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the words' stems doesn't fit its declension.
+    /// See [`NounPhrase::try_inflect`].
+    #[must_use]
+    pub fn inflect(&self, case: CaseEx, number: Number) -> String {
+        unwrap_inflected(self.try_inflect(case, number))
+    }
+    /// Inflects the phrase into the specified case and number, joining the words with spaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if any of the words couldn't be inflected.
+    pub fn try_inflect(&self, case: CaseEx, number: Number) -> Result<String, InflectError> {
+        let words = self.try_inflect_words(case, number)?;
+        Ok(words.iter().map(WordBuf::as_str).collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// Constructs a phrase from stressed words and their infos, for use in tests.
+#[cfg(test)]
+pub(crate) fn phrase(adjectives: &[(&str, &str)], noun: (&str, &str)) -> NounPhrase {
+    let adjectives = adjectives
+        .iter()
+        .map(|&(word, info)| Adjective::from_word(word, info.parse().unwrap()).unwrap());
+    let noun = Noun::from_word(noun.0, noun.1.parse().unwrap()).unwrap();
+    NounPhrase { adjectives: adjectives.collect(), ..NounPhrase::new(noun) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{categories::Case, noun::NounInfo};
+
+    #[test]
+    fn agreement() {
+        // Gender and animacy
+        let x = phrase(&[("но́вый", "п 1a")], ("сто́л", "м 1b"));
+        assert_eq!(x.inflect(CaseEx::Accusative, Number::Singular), "новый стол");
+        let x = phrase(&[("но́вый", "п 1a")], ("учени́к", "мо 3b"));
+        assert_eq!(x.inflect(CaseEx::Accusative, Number::Singular), "нового ученика");
+        let x = phrase(&[("но́вый", "п 1a")], ("кни́га", "ж 3a"));
+        assert_eq!(x.inflect(CaseEx::Instrumental, Number::Singular), "новой книгой");
+        assert_eq!(x.inflect(CaseEx::Accusative, Number::Plural), "новые книги");
+
+        // Agreement gender differs from declension gender
+        let x = phrase(&[("краси́вый", "п 1a")], ("мужчи́на", "мо <жо 1a>"));
+        assert_eq!(x.inflect(CaseEx::Nominative, Number::Singular), "красивый мужчина");
+        assert_eq!(x.inflect(CaseEx::Instrumental, Number::Singular), "красивым мужчиной");
+
+        // Pluralia tantum
+        let x = phrase(&[("о́стрый", "п 1a")], ("но́жницы", "мн. <ж 5a>"));
+        assert_eq!(x.inflect(CaseEx::Instrumental, Number::Singular), "острыми ножницами");
+
+        // Several adjectives
+        let x = phrase(&[("кра́сный", "п 1*a/c′"), ("но́вый", "п 1a")], ("окно́", "с 1*d"));
+        assert_eq!(x.inflect(CaseEx::Prepositional, Number::Singular), "красном новом окне");
+        assert_eq!(x.inflect_words(CaseEx::Genitive, Number::Plural).len(), 3);
+
+        // Secondary cases
+        let x = phrase(&[("густо́й", "п 1b")], ("лес", "м 1c, П2 (в)"));
+        let info = x.agreement(CaseEx::Locative, Number::Singular);
+        assert_eq!((info.case, info.number), (Case::Prepositional, Number::Singular));
+        assert_eq!(x.inflect_words(CaseEx::Locative, Number::Singular)[1].as_str(), "лесу");
+    }
+
+    #[test]
+    fn common_gender() {
+        let mut x = phrase(&[("бе́дный", "п 1*a/c′")], ("сирота́", "мо-жо 1d"));
+        assert_eq!(x.agreement(CaseEx::Dative, Number::Singular).gender, Gender::Feminine);
+        assert_eq!(x.inflect(CaseEx::Dative, Number::Singular), "бедной сироте");
+
+        x.referent = Some(Gender::Masculine);
+        assert_eq!(x.inflect(CaseEx::Dative, Number::Singular), "бедному сироте");
+        assert_eq!(x.inflect(CaseEx::Dative, Number::Plural), "бедным сиротам");
+
+        // Without a referent, the declension gender is used
+        let noun = Noun::from_stem(x.noun.stem().to_owned(), NounInfo {
+            declension_gender: Gender::Masculine,
+            ..x.noun.info()
+        });
+        let x = NounPhrase::new(noun);
+        assert_eq!(x.agreement(CaseEx::Dative, Number::Singular).gender, Gender::Masculine);
+
+        // The referent doesn't affect nouns of other genders
+        let mut x = phrase(&[("хоро́ший", "п 4a")], ("врач", "мо 4b"));
+        x.referent = Some(Gender::Feminine);
+        assert_eq!(x.inflect(CaseEx::Nominative, Number::Singular), "хороший врач");
+    }
+
+    #[test]
+    fn errors() {
+        let noun = Noun::from_word("сто́л", "м 1°b".parse().unwrap()).unwrap();
+        let x = NounPhrase::new(noun);
+        assert_eq!(
+            x.try_inflect(CaseEx::Genitive, Number::Singular),
+            Err(InflectError::InvalidUniqueAlternation),
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! Prepositions, and their euphonic variants.
+//!
+//! Some prepositions have variants, that are used before certain words to make pronunciation
+//! easier: **о** becomes **об** before vowels (**об ослах**), and **в**, **к**, **с** take an
+//! extra **-о** before some consonant clusters (**во вторник**, **со стола**, **ко мне**).
+//! Since the variant depends on the first letters of the following word, it can only be selected
+//! after the word has been inflected.
+//!
+//! # Examples
+//!
+//! ```
+//! use zaliznyak::{
+//!     categories::{CaseEx, Number},
+//!     noun::Noun,
+//!     preposition::Preposition,
+//! };
+//!
+//! let noun = Noun::from_word("осёл", "мо 1*b".parse().unwrap()).unwrap();
+//! let word = noun.inflect(CaseEx::Prepositional, Number::Plural);
+//! assert_eq!(Preposition::О.before(word.as_letters()), "об"); // об ослах
+//!
+//! let noun = Noun::from_word("сто́л", "м 1b".parse().unwrap()).unwrap();
+//! let word = noun.inflect(CaseEx::Genitive, Number::Singular);
+//! assert_eq!(Preposition::С.before(word.as_letters()), "со"); // со стола
+//! ```
+use crate::word::Utf8Letter;
+
+/// A preposition that has euphonic variants: **о**/**об**/**обо**, **в**/**во**, **к**/**ко**,
+/// **с**/**со**, **над**/**надо**, **под**/**подо**, **перед**/**передо**.
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum Preposition {
+    /// **в**/**во** (in, into).
+    В,
+    /// **к**/**ко** (to, towards).
+    К,
+    /// **над**/**надо** (above).
+    Над,
+    /// **о**/**об**/**обо** (about).
+    О,
+    /// **перед**/**передо** (in front of, before).
+    Перед,
+    /// **под**/**подо** (under).
+    Под,
+    /// **с**/**со** (with, from).
+    С,
+}
+
+impl Preposition {
+    /// Returns the preposition's main form: **в**, **о**, **с**.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::В => "в",
+            Self::К => "к",
+            Self::Над => "над",
+            Self::О => "о",
+            Self::Перед => "перед",
+            Self::Под => "под",
+            Self::С => "с",
+        }
+    }
+
+    /// Returns the preposition's variant, used before the specified word. The word must already
+    /// be inflected into the case the preposition governs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{
+    ///     preposition::Preposition,
+    ///     word::WordBuf,
+    /// };
+    ///
+    /// let word: WordBuf = "вто́рник".parse().unwrap();
+    /// assert_eq!(Preposition::В.before(word.as_letters()), "во");
+    /// assert_eq!(Preposition::К.before(word.as_letters()), "ко");
+    /// assert_eq!(Preposition::О.before(word.as_letters()), "о");
+    /// ```
+    #[must_use]
+    pub const fn before(self, word: &[Utf8Letter]) -> &'static str {
+        use Utf8Letter::*;
+
+        let extended = match self {
+            Self::О => {
+                return match word {
+                    [М, Н, Е] | [Ч, Т, О] => "обо",
+                    [В, С, Ё] | [В, С, Ё, М] | [В, С, Е, Х] | [В, С, Ю] => "обо",
+                    [А | И | О | У | Э, ..] => "об",
+                    _ => "о",
+                };
+            },
+            // во вторник, во Франции, во мне, во многом
+            Self::В => match word {
+                [В | Ф, second, ..] => second.is_consonant(),
+                [М, Н, ..] | [Ч, Т, О] | [С, Н, Е] => true,
+                [Р, Т, У] | [Л, Б, У] | [Р, Ж, И] | [Л, Ь, Д, У] => true,
+                _ => false,
+            },
+            // ко мне, ко всем, ко вторнику
+            Self::К => matches!(
+                word,
+                [М, Н, ..] | [В, С | Т, ..] | [Д, Н, Ю] | [Р, Т, У] | [С, Н, У] | [Л, Ь, Д, У]
+            ),
+            // со стола, со шкафа, со мной, со всеми
+            Self::С => match word {
+                [С | З | Ш | Ж | Щ, second, ..] => second.is_consonant(),
+                [М, Н, ..] | [В, С, ..] => true,
+                [Д, Н, Я | А] | [Л, Б, А] | [Р, Т, А] | [Л, Ь, Д, А] => true,
+                _ => false,
+            },
+            // надо мной, подо всем, передо мною
+            Self::Над | Self::Перед | Self::Под => {
+                matches!(word, [М, Н, Е] | [М, Н, О, Й | Ю] | [В, С, ..])
+            },
+        };
+
+        match (extended, self) {
+            (false, _) => self.as_str(),
+            (true, Self::В) => "во",
+            (true, Self::К) => "ко",
+            (true, Self::Над) => "надо",
+            (true, Self::Перед) => "передо",
+            (true, Self::Под) => "подо",
+            (true, _) => "со",
+        }
+    }
+
+    /// Returns the preposition's variant, used before the specified word. The word must already
+    /// be inflected into the case the preposition governs. Uppercase letters and stress marks
+    /// (U+0301 and U+0300) are allowed, and the main form is returned for words that aren't
+    /// spelled in Cyrillic.
+    ///
+    /// See also [`Preposition::before`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{
+    ///     categories::{Case, Number},
+    ///     preposition::Preposition,
+    ///     pronoun::PersonalPronoun,
+    /// };
+    ///
+    /// let me = PersonalPronoun::First(Number::Singular);
+    /// let word = me.inflect_after_preposition(Case::Dative);
+    /// assert_eq!(Preposition::К.before_str(word), "ко"); // ко мне
+    ///
+    /// assert_eq!(Preposition::О.before_str("Австралии"), "об");
+    /// assert_eq!(Preposition::С.before_str("сто́ла"), "со");
+    /// assert_eq!(Preposition::В.before_str("Vienna"), "в");
+    /// ```
+    #[must_use]
+    pub fn before_str(self, word: &str) -> &'static str {
+        let letters: Option<Vec<Utf8Letter>> = (word.chars())
+            .filter(|&ch| ch != '\u{301}' && ch != '\u{300}')
+            .flat_map(char::to_lowercase)
+            .map(Utf8Letter::from_char)
+            .collect();
+
+        match letters {
+            Some(letters) => self.before(&letters),
+            None => self.as_str(),
+        }
+    }
+}
+
+impl std::fmt::Display for Preposition {
+    /// Formats the preposition's main form: `в`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variants() {
+        let cases = [
+            (Preposition::О, "ослах", "об"),
+            (Preposition::О, "иве", "об"),
+            (Preposition::О, "ёлке", "о"),
+            (Preposition::О, "мне", "обо"),
+            (Preposition::О, "всём", "обо"),
+            (Preposition::О, "многом", "о"),
+            (Preposition::В, "вторник", "во"),
+            (Preposition::В, "франции", "во"),
+            (Preposition::В, "вагоне", "в"),
+            (Preposition::В, "мне", "во"),
+            (Preposition::В, "сне", "во"),
+            (Preposition::В, "столе", "в"),
+            (Preposition::К, "мне", "ко"),
+            (Preposition::К, "всем", "ко"),
+            (Preposition::К, "вечеру", "к"),
+            (Preposition::К, "кому", "к"),
+            (Preposition::С, "стола", "со"),
+            (Preposition::С, "звездой", "со"),
+            (Preposition::С, "шкафа", "со"),
+            (Preposition::С, "мной", "со"),
+            (Preposition::С, "сахаром", "с"),
+            (Preposition::С, "дня", "со"),
+            (Preposition::С, "днём", "с"),
+            (Preposition::Над, "мной", "надо"),
+            (Preposition::Над, "многими", "над"),
+            (Preposition::Под, "всем", "подо"),
+            (Preposition::Перед, "мною", "передо"),
+            (Preposition::Перед, "домом", "перед"),
+        ];
+        for (preposition, word, expected) in cases {
+            assert_eq!(preposition.before_str(word), expected, "{preposition} {word}");
+        }
+
+        assert_eq!(Preposition::О.before_str("Эрмитаже"), "об");
+        assert_eq!(Preposition::С.before_str("СТОЛА"), "со");
+        assert_eq!(Preposition::С.before_str("сто́ла"), "со");
+        assert_eq!(Preposition::В.before_str("Вто́рник"), "во");
+        assert_eq!(Preposition::О.before_str("о̀слах"), "об");
+        assert_eq!(Preposition::О.before_str("Apple"), "о");
+        assert_eq!(Preposition::В.before(&[]), "в");
+    }
+}
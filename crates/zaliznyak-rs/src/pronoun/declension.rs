@@ -1,37 +1,69 @@
 use crate::{
     categories::{DeclInfo, Gender, IntoNumber},
-    declension::{Declension, PronounDeclension},
+    declension::{Declension, InflectError, PronounDeclension, unwrap_inflected},
     pronoun::{Pronoun, PronounInfo},
     util::InflectionBuf,
     word::{Utf8Letter, Utf8LetterSlice, Word, WordBuf},
 };
 
 impl Pronoun {
+    /// Inflects the pronoun.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pronoun's stem doesn't fit its declension. See [`Pronoun::try_inflect`].
     pub fn inflect(&self, info: DeclInfo) -> WordBuf {
         self.info.inflect(self.stem.borrow(), info)
     }
+    /// Inflects the pronoun, returning an error instead of panicking if the pronoun's stem
+    /// doesn't fit its declension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if the pronoun's declension couldn't be applied to its stem.
+    pub fn try_inflect(&self, info: DeclInfo) -> Result<WordBuf, InflectError> {
+        self.info.try_inflect(self.stem.borrow(), info)
+    }
 }
 
 impl PronounInfo {
+    /// Inflects a pronoun with the specified stem.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stem doesn't fit the declension. See [`PronounInfo::try_inflect`].
     pub fn inflect(&self, stem: Word, info: DeclInfo) -> WordBuf {
+        unwrap_inflected(self.try_inflect(stem, info))
+    }
+    /// Inflects a pronoun with the specified stem.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InflectError`] if the declension couldn't be applied to the stem.
+    pub fn try_inflect(&self, stem: Word, info: DeclInfo) -> Result<WordBuf, InflectError> {
         let mut word = WordBuf::with_stem(stem, 5);
         let mut buf = InflectionBuf::new(&mut word);
 
         if let Some(decl) = self.declension {
             match decl {
-                Declension::Pronoun(decl) => decl.inflect(info, &mut buf),
-                Declension::Adjective(decl) => decl.inflect(info, &mut buf),
-                Declension::Noun(_) => unimplemented!(), // Pronouns don't decline by noun declension
+                Declension::Pronoun(decl) => decl.inflect(info, &mut buf)?,
+                Declension::Adjective(decl) => decl.inflect(info, &mut buf)?,
+                // Pronouns don't decline by noun declension
+                Declension::Noun(_) => return Err(InflectError::IncompatibleDeclension),
             };
         }
 
         buf.finish(&mut word);
-        word
+        Ok(word)
     }
 }
 
 impl PronounDeclension {
-    pub(crate) fn inflect(self, info: DeclInfo, buf: &mut InflectionBuf) {
+    pub(crate) fn inflect(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
         // Determine the stress position
         buf.stress = self.stress.pos(info);
 
@@ -39,23 +71,28 @@ impl PronounDeclension {
         buf.append_to_ending(self.find_ending(info).as_str());
 
         if self.flags.has_star() {
-            self.apply_vowel_alternation(info, buf);
+            self.apply_vowel_alternation(info, buf)?;
         }
+        Ok(())
     }
 
-    fn apply_vowel_alternation(self, info: DeclInfo, buf: &mut InflectionBuf) {
+    fn apply_vowel_alternation(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuf,
+    ) -> Result<(), InflectError> {
         // Vowel alternation type A
         // Singular masculine nominative form is unchanged
         if info.is_singular()
             && info.gender == Gender::Masculine
             && info.case.is_nom_or_acc_inan(info)
         {
-            return;
+            return Ok(());
         }
 
         // Find the alternating LAST vowel
         let Some(found) = buf.stem_mut().iter_mut().enumerate().rfind(|x| x.1.is_vowel()) else {
-            todo!("Handle absence of vowels in the stem?")
+            return Err(InflectError::NoVowelInStem);
         };
         let (vowel_index, vowel) = found;
 
@@ -72,7 +109,7 @@ impl PronounDeclension {
                 *vowel = Utf8Letter::Ь;
             },
             Utf8Letter::Е | Utf8Letter::Ё => {
-                let preceding = buf.stem().get(vowel_index - 1).copied();
+                let preceding = vowel_index.checked_sub(1).and_then(|x| buf.stem().get(x)).copied();
 
                 if preceding.is_some_and(|x| x.is_vowel()) {
                     // 1) is replaced with 'й' when after a vowel
@@ -85,9 +122,8 @@ impl PronounDeclension {
                     buf.remove_stem_char_at(vowel_index);
                 }
             },
-            _ => {
-                todo!("Handle invalid vowel alternation")
-            },
+            _ => return Err(InflectError::InvalidVowelAlternation),
         };
+        Ok(())
     }
 }
@@ -0,0 +1,107 @@
+use crate::{
+    declension::{
+        AdjectiveDeclension, DECLENSION_MAX_LEN, Declension, NounDeclension, PronounDeclension,
+    },
+    pronoun::PronounInfo,
+    util::UnsafeBuf,
+};
+
+// Longest form: мс <п 6°*f″/f″①②③, ё> (36 bytes, 21 chars)
+// Max additions: мс <п > (+10 bytes, +7 chars)
+pub const PRONOUN_INFO_MAX_LEN: usize = DECLENSION_MAX_LEN + 10;
+
+impl PronounInfo {
+    pub const fn fmt_to<'a>(&self, dst: &'a mut [u8; PRONOUN_INFO_MAX_LEN]) -> &'a mut str {
+        let mut dst = UnsafeBuf::new(dst);
+
+        dst.push_str("мс ");
+
+        match self.declension {
+            Some(Declension::Pronoun(decl)) => {
+                dst.push_fmt2(decl, PronounDeclension::fmt_to);
+            },
+            Some(Declension::Adjective(decl)) => {
+                // Include adjective declension in brackets
+                dst.push_str("<п ");
+                dst.push_fmt2(decl, AdjectiveDeclension::fmt_to);
+                dst.push('>');
+            },
+            Some(Declension::Noun(decl)) => {
+                // Pronouns don't decline by noun declension, and such info can't be parsed back.
+                //   Still, format it in brackets, so that Display never panics.
+                dst.push('<');
+                dst.push_fmt2(decl, NounDeclension::fmt_to);
+                dst.push('>');
+            },
+            None => dst.push('0'),
+        };
+
+        dst.finish()
+    }
+}
+
+impl std::fmt::Display for PronounInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.fmt_to(&mut [0; _]).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        declension::{AdjectiveStemType, DeclensionFlags, NounStemType, PronounStemType},
+        stress::{AdjectiveStress, NounStress, PronounStress},
+    };
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            PronounInfo {
+                declension: Some(Declension::Pronoun(PronounDeclension {
+                    stem_type: PronounStemType::Type1,
+                    stress: PronounStress::A,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }
+            .to_string(),
+            "мс 1a",
+        );
+        assert_eq!(
+            PronounInfo {
+                declension: Some(Declension::Pronoun(PronounDeclension {
+                    stem_type: PronounStemType::Type6,
+                    stress: PronounStress::B,
+                    flags: DeclensionFlags::STAR,
+                })),
+            }
+            .to_string(),
+            "мс 6*b",
+        );
+        assert_eq!(
+            PronounInfo {
+                declension: Some(Declension::Adjective(AdjectiveDeclension {
+                    stem_type: AdjectiveStemType::Type3,
+                    stress: AdjectiveStress::B,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }
+            .to_string(),
+            "мс <п 3b>",
+        );
+        assert_eq!(PronounInfo { declension: None }.to_string(), "мс 0");
+
+        // Incompatible declensions are still formatted
+        assert_eq!(
+            PronounInfo {
+                declension: Some(Declension::Noun(NounDeclension {
+                    stem_type: NounStemType::Type1,
+                    stress: NounStress::A,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }
+            .to_string(),
+            "мс <1a>",
+        );
+    }
+}
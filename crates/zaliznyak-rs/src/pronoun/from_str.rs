@@ -0,0 +1,108 @@
+use crate::{
+    declension::{AdjectiveDeclension, Declension, ParseDeclensionError, PronounDeclension},
+    pronoun::PronounInfo,
+    util::{PartialFromStr, UnsafeParser},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum ParsePronounInfoError {
+    #[error("invalid pronoun type")]
+    InvalidKind,
+    #[error("error parsing declension: {0}")]
+    InvalidDeclension(ParseDeclensionError),
+    #[error("declension type not compatible with pronoun type")]
+    IncompatibleDeclension,
+    #[error("invalid format")]
+    Invalid,
+}
+
+impl const PartialFromStr for PronounInfo {
+    fn partial_from_str(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        if !parser.skip_str("мс ") {
+            return Err(Self::Err::InvalidKind);
+        }
+
+        let declension = if parser.skip('0') {
+            None
+        } else if parser.skip('<') {
+            // Pronouns can only have adjective declension in brackets
+            if !parser.skip_str("п ") {
+                return Err(Self::Err::IncompatibleDeclension);
+            }
+            let decl = AdjectiveDeclension::partial_from_str(parser)
+                .map_err(Self::Err::InvalidDeclension)?;
+
+            if !parser.skip('>') {
+                return Err(Self::Err::Invalid);
+            }
+            Some(Declension::Adjective(decl))
+        } else {
+            let decl = PronounDeclension::partial_from_str(parser)
+                .map_err(Self::Err::InvalidDeclension)?;
+            Some(Declension::Pronoun(decl))
+        };
+
+        Ok(Self { declension })
+    }
+}
+
+impl const std::str::FromStr for PronounInfo {
+    type Err = ParsePronounInfoError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_or_err(s, Self::Err::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        declension::{AdjectiveStemType, DeclensionFlags, PronounStemType},
+        stress::{AdjectiveStress, PronounStress},
+    };
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "мс 1a".parse(),
+            Ok(PronounInfo {
+                declension: Some(Declension::Pronoun(PronounDeclension {
+                    stem_type: PronounStemType::Type1,
+                    stress: PronounStress::A,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }),
+        );
+        assert_eq!(
+            "мс <п 3b>".parse(),
+            Ok(PronounInfo {
+                declension: Some(Declension::Adjective(AdjectiveDeclension {
+                    stem_type: AdjectiveStemType::Type3,
+                    stress: AdjectiveStress::B,
+                    flags: DeclensionFlags::empty(),
+                })),
+            }),
+        );
+        assert_eq!("мс 0".parse(), Ok(PronounInfo { declension: None }));
+
+        // Round-trip formatting
+        for s in ["мс 1a", "мс 6*b", "мс 2*f", "мс <п 1a>", "мс 0"] {
+            assert_eq!(s.parse::<PronounInfo>().unwrap().to_string(), s);
+        }
+
+        // Invalid formats
+        assert_eq!("п 1a".parse::<PronounInfo>(), Err(ParsePronounInfoError::InvalidKind));
+        assert_eq!(
+            "мс <м 1a>".parse::<PronounInfo>(),
+            Err(ParsePronounInfoError::IncompatibleDeclension)
+        );
+        assert!(matches!(
+            "мс 3a".parse::<PronounInfo>(),
+            Err(ParsePronounInfoError::InvalidDeclension(_))
+        ));
+        assert_eq!("мс <п 1a".parse::<PronounInfo>(), Err(ParsePronounInfoError::Invalid));
+        assert_eq!("мс 1a~".parse::<PronounInfo>(), Err(ParsePronounInfoError::Invalid));
+    }
+}
@@ -1,7 +1,16 @@
-use crate::{declension::Declension, word::WordBuf};
+use crate::{
+    declension::Declension,
+    word::{Word, WordBuf},
+};
 use thiserror::Error;
 
 mod declension;
+mod fmt;
+mod from_str;
+mod personal;
+
+pub use from_str::*;
+pub use personal::*;
 
 #[derive(Debug, Clone, Eq, Hash)]
 #[derive_const(PartialEq)]
@@ -29,4 +38,33 @@ impl Pronoun {
     pub const fn from_stem(stem: WordBuf, info: PronounInfo) -> Self {
         Self { stem, info }
     }
+    /// Constructs a pronoun from its nominative form, identifying its stem according to
+    /// the pronoun's declension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NewPronounError::InvalidStem`] if the word is not a valid stressed word or its
+    /// stem couldn't be identified, and [`NewPronounError::NotMatchingStemType`] if the identified
+    /// stem type doesn't match the declension's stem type.
+    pub fn from_word(word: &str, info: PronounInfo) -> Result<Self, NewPronounError> {
+        let mut stem: WordBuf = word.parse().or(Err(NewPronounError::InvalidStem))?;
+
+        if let Some(decl) = info.declension {
+            let (matches, _) = decl.identify_trim(&mut stem).ok_or(NewPronounError::InvalidStem)?;
+            if !matches {
+                return Err(NewPronounError::NotMatchingStemType);
+            }
+        }
+        Ok(Self { stem, info })
+    }
+    /// Returns the pronoun's stem.
+    #[must_use]
+    pub const fn stem(&self) -> Word<'_> {
+        self.stem.borrow()
+    }
+    /// Returns the pronoun's grammatical info.
+    #[must_use]
+    pub const fn info(&self) -> PronounInfo {
+        self.info
+    }
 }
@@ -0,0 +1,163 @@
+use crate::categories::{Case, Gender, Number, Person};
+
+/// A personal pronoun (я, ты, он, она, оно, мы, вы, они), or the reflexive pronoun себя.
+///
+/// Personal pronouns are suppletive, --- their forms don't share a common stem (я, меня, мне),
+/// and so they're declined by a built-in table instead of a
+/// [`PronounDeclension`][crate::declension::PronounDeclension].
+///
+/// Third person pronouns' forms starting with a vowel take an **н-** prefix after prepositions:
+/// **его** (him) --- **у него** (at him), **им** --- **с ним**.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{Case, Gender, Number, Person},
+///     pronoun::PersonalPronoun,
+/// };
+///
+/// let she = PersonalPronoun::new(Person::Third, Number::Singular, Gender::Feminine);
+/// assert_eq!(she.inflect(Case::Genitive), "её");
+/// assert_eq!(she.inflect_after_preposition(Case::Genitive), "неё");
+///
+/// assert_eq!(PersonalPronoun::First(Number::Singular).inflect(Case::Dative), "мне");
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum PersonalPronoun {
+    /// First person: я, мы.
+    First(Number),
+    /// Second person: ты, вы.
+    Second(Number),
+    /// Third person: он, оно, она, они. The gender is ignored in plural.
+    Third(Number, Gender),
+    /// The reflexive pronoun себя, that refers to the subject of the clause.
+    Reflexive,
+}
+
+impl PersonalPronoun {
+    /// Returns the personal pronoun of the specified person, number and gender.
+    #[must_use]
+    pub const fn new(person: Person, number: Number, gender: Gender) -> Self {
+        match person {
+            Person::First => Self::First(number),
+            Person::Second => Self::Second(number),
+            Person::Third => Self::Third(number, gender),
+        }
+    }
+
+    /// Returns the pronoun's form in the specified case. Since the reflexive pronoun doesn't have
+    /// a nominative form, its dictionary form **себя** is returned instead.
+    #[must_use]
+    pub const fn inflect(self, case: Case) -> &'static str {
+        self.forms()[case as usize]
+    }
+    /// Returns the pronoun's form in the specified case, used after prepositions: third person
+    /// pronouns' forms take an **н-** prefix (**него**, **к ней**, **с ними**), while the other
+    /// pronouns' forms are the same as [`inflect`][PersonalPronoun::inflect]'s.
+    #[must_use]
+    pub const fn inflect_after_preposition(self, case: Case) -> &'static str {
+        match self {
+            Self::Third(number, gender) => {
+                let forms = &THIRD_AFTER_PREPOSITION[third_index(number, gender)];
+                forms[case as usize]
+            },
+            _ => self.inflect(case),
+        }
+    }
+
+    const fn forms(self) -> &'static [&'static str; 6] {
+        match self {
+            Self::First(Number::Singular) => &FIRST[0],
+            Self::First(Number::Plural) => &FIRST[1],
+            Self::Second(Number::Singular) => &SECOND[0],
+            Self::Second(Number::Plural) => &SECOND[1],
+            Self::Third(number, gender) => &THIRD[third_index(number, gender)],
+            Self::Reflexive => &REFLEXIVE,
+        }
+    }
+}
+
+const fn third_index(number: Number, gender: Gender) -> usize {
+    match number {
+        Number::Singular => gender as usize,
+        Number::Plural => 3,
+    }
+}
+
+impl std::fmt::Display for PersonalPronoun {
+    /// Formats the pronoun in the nominative case: `она`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.inflect(Case::Nominative))
+    }
+}
+
+// Forms in order of cases: nominative, genitive, dative, accusative, instrumental, prepositional.
+//   Prepositional forms are only used after prepositions, so they always have the н- prefix.
+#[rustfmt::skip]
+const FIRST: [[&str; 6]; 2] = [
+    ["я", "меня", "мне", "меня", "мной", "мне"],
+    ["мы", "нас", "нам", "нас", "нами", "нас"],
+];
+#[rustfmt::skip]
+const SECOND: [[&str; 6]; 2] = [
+    ["ты", "тебя", "тебе", "тебя", "тобой", "тебе"],
+    ["вы", "вас", "вам", "вас", "вами", "вас"],
+];
+#[rustfmt::skip]
+const THIRD: [[&str; 6]; 4] = [
+    ["он", "его", "ему", "его", "им", "нём"],
+    ["оно", "его", "ему", "его", "им", "нём"],
+    ["она", "её", "ей", "её", "ей", "ней"],
+    ["они", "их", "им", "их", "ими", "них"],
+];
+#[rustfmt::skip]
+const THIRD_AFTER_PREPOSITION: [[&str; 6]; 4] = [
+    ["он", "него", "нему", "него", "ним", "нём"],
+    ["оно", "него", "нему", "него", "ним", "нём"],
+    ["она", "неё", "ней", "неё", "ней", "ней"],
+    ["они", "них", "ним", "них", "ними", "них"],
+];
+const REFLEXIVE: [&str; 6] = ["себя", "себя", "себе", "себя", "собой", "себе"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inflect() {
+        let forms = |x: PersonalPronoun| Case::VALUES.map(|case| x.inflect(case)).join(" ");
+
+        let i = PersonalPronoun::new(Person::First, Number::Singular, Gender::Masculine);
+        assert_eq!(forms(i), "я меня мне меня мной мне");
+        let you = PersonalPronoun::new(Person::Second, Number::Plural, Gender::Feminine);
+        assert_eq!(forms(you), "вы вас вам вас вами вас");
+        let it = PersonalPronoun::new(Person::Third, Number::Singular, Gender::Neuter);
+        assert_eq!(forms(it), "оно его ему его им нём");
+        let they = PersonalPronoun::Third(Number::Plural, Gender::Feminine);
+        assert_eq!(forms(they), "они их им их ими них");
+        assert_eq!(forms(PersonalPronoun::Reflexive), "себя себя себе себя собой себе");
+
+        assert_eq!(PersonalPronoun::Third(Number::Singular, Gender::Masculine).to_string(), "он");
+    }
+
+    #[test]
+    fn after_preposition() {
+        let forms = |x: PersonalPronoun| {
+            Case::VALUES.map(|case| x.inflect_after_preposition(case)).join(" ")
+        };
+
+        let he = PersonalPronoun::Third(Number::Singular, Gender::Masculine);
+        assert_eq!(forms(he), "он него нему него ним нём");
+        let she = PersonalPronoun::Third(Number::Singular, Gender::Feminine);
+        assert_eq!(forms(she), "она неё ней неё ней ней");
+        let they = PersonalPronoun::Third(Number::Plural, Gender::Masculine);
+        assert_eq!(forms(they), "они них ним них ними них");
+
+        // Other pronouns don't change after prepositions
+        let we = PersonalPronoun::First(Number::Plural);
+        assert_eq!(forms(we), "мы нас нам нас нами нас");
+        assert_eq!(PersonalPronoun::Reflexive.inflect_after_preposition(Case::Dative), "себе");
+    }
+}
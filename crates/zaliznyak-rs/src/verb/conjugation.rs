@@ -0,0 +1,781 @@
+use crate::{
+    categories::{ConjInfo, Gender, IntoNumber, IntoPerson, Number, Person, Tense},
+    conjugation::{Conjugation, ConjugationClass},
+    stress::StressPos,
+    util::InflectionBuf,
+    verb::{Verb, VerbInfo},
+    word::{Utf8Letter, Word, WordBuf},
+};
+
+impl Verb {
+    /// Conjugates the verb into the specified tense, number, person (in the present tense) or
+    /// gender (in the past tense).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{
+    ///     categories::{ConjInfo, Gender, Number, Person, Tense},
+    ///     verb::Verb,
+    /// };
+    ///
+    /// let verb = Verb::from_word("нести́", "нсв 7b/b, ё".parse().unwrap()).unwrap();
+    ///
+    /// let (number, person) = (Number::Singular, Person::Third);
+    /// let info = ConjInfo { tense: Tense::Present, number, person, gender: Gender::Masculine };
+    /// assert_eq!(verb.inflect(info).as_str(), "несёт");
+    ///
+    /// let info = ConjInfo { tense: Tense::Past, number, person, gender: Gender::Feminine };
+    /// assert_eq!(verb.inflect(info).as_str(), "несла");
+    /// ```
+    pub fn inflect(&self, info: ConjInfo) -> WordBuf {
+        self.info.inflect(self.stem.borrow(), info)
+    }
+    /// Forms the verb's imperative in the specified number.
+    ///
+    /// Returns `None` if the verb doesn't conjugate, or if its imperative is difficult to form
+    /// (marked with ⑨) and `force` is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{categories::Number, verb::Verb};
+    ///
+    /// let verb = Verb::from_word("писа́ть", "нсв 6c".parse().unwrap()).unwrap();
+    /// let word = verb.inflect_imperative(Number::Plural, false).unwrap();
+    /// assert_eq!(word.as_str(), "пишите");
+    ///
+    /// let verb = Verb::from_word("мо́чь", "нсв-нп 8c/b(-г-)⑨".parse().unwrap()).unwrap();
+    /// assert_eq!(verb.inflect_imperative(Number::Singular, false), None);
+    /// assert_eq!(verb.inflect_imperative(Number::Singular, true).unwrap().as_str(), "моги");
+    /// ```
+    pub fn inflect_imperative(&self, number: Number, force: bool) -> Option<WordBuf> {
+        self.info.inflect_imperative(self.stem.borrow(), number, force)
+    }
+}
+
+impl VerbInfo {
+    /// Conjugates a verb with the specified stem. See [`Verb::inflect`].
+    pub fn inflect(&self, stem: Word, info: ConjInfo) -> WordBuf {
+        let mut word = WordBuf::with_stem(stem, 8);
+        let mut buf = InflectionBuf::new(&mut word);
+
+        if let Some(conj) = self.conjugation {
+            conj.inflect(info, &mut buf);
+        }
+        if self.reflexive {
+            append_reflexive_postfix(&mut buf);
+        }
+
+        buf.finish(&mut word);
+        word
+    }
+
+    /// Forms the imperative of a verb with the specified stem. See [`Verb::inflect_imperative`].
+    pub fn inflect_imperative(&self, stem: Word, number: Number, force: bool) -> Option<WordBuf> {
+        // Check the conjugation flag (⑨) to see if there are difficulties with the imperative
+        let conj = self.conjugation?;
+        if conj.flags.has_difficult_imperative() && !force {
+            return None;
+        }
+
+        let mut word = WordBuf::with_stem(stem, 8);
+        let mut buf = InflectionBuf::new(&mut word);
+
+        conj.inflect_imperative(number, &mut buf);
+        if self.reflexive {
+            append_reflexive_postfix(&mut buf);
+        }
+
+        buf.finish(&mut word);
+        Some(word)
+    }
+}
+
+/// Appends the reflexive postfix: -ся after consonants (учится, учился, учись), and -сь after
+/// vowels (учусь, училась, учитесь).
+pub(super) fn append_reflexive_postfix(buf: &mut InflectionBuf) {
+    let after_vowel = buf.as_slice().last().is_some_and(|x| x.is_vowel());
+    buf.append_to_ending(if after_vowel { "сь" } else { "ся" });
+}
+
+impl Conjugation {
+    pub(crate) fn inflect(self, info: ConjInfo, buf: &mut InflectionBuf) {
+        // Remember the stressed letter of the infinitive stem
+        let stress_at = buf.stress_at;
+
+        match info.tense {
+            Tense::Present => self.inflect_present(info, buf),
+            Tense::Past => self.inflect_past(info, buf),
+        };
+
+        self.place_stress(stress_at, buf);
+
+        if self.flags.has_alternating_yo() {
+            self.apply_ye_yo_alternation(buf);
+        }
+    }
+
+    pub(crate) fn inflect_imperative(self, number: Number, buf: &mut InflectionBuf) {
+        use {ConjugationClass::*, Utf8Letter::*};
+
+        // Remember the stressed letter of the infinitive stem
+        let stress_at = buf.stress_at;
+
+        // The imperative is stressed the same way as the 1st person singular present form
+        buf.stress = self.stress.present.pos(Number::Singular, Person::First);
+
+        // The imperative is formed from the 3rd person plural present tense stem,
+        // except for class 13, that keeps the infinitive stem (давать - давай, вставать - вставай).
+        if self.class != Class13 {
+            let (tense, gender) = (Tense::Present, Gender::Masculine);
+            let info = ConjInfo { tense, number: Number::Plural, person: Person::Third, gender };
+            self.form_present_stem(info, buf);
+        }
+
+        // бить - бей, пить - пей
+        if let Some(ch @ Ь) = buf.stem_mut().last_mut() {
+            *ch = Е;
+        }
+
+        let ending = match buf.stem() {
+            // читать - читай, рисовать - рисуй, стоять - стой, бить - бей
+            [.., x] if x.is_vowel() => "й",
+            // писать - пиши, нести - неси, жить - живи
+            _ if buf.is_ending_stressed() => "и",
+            // сыпать - сыпь, with the epenthetic 'л' dropped
+            [.., Б | П | В | Ф | М, Л] if self.class == Class6 => {
+                buf.shrink_stem_by(1);
+                "ь"
+            },
+            // крикнуть - крикни, чистить - чисти, помнить - помни
+            [.., x, y] if x.is_consonant() && y.is_consonant() => "и",
+            // лечь (ляг-у) - ляг
+            [.., Г | К] => "",
+            // плакать - плачь, верить - верь, стать - стань
+            _ => "ь",
+        };
+        buf.append_to_ending(ending);
+
+        // Only the 'и' ending can be stressed, the others don't have a vowel (чита́й, ку́йте)
+        if ending != "и" {
+            buf.stress = StressPos::Stem;
+        }
+
+        if number == Number::Plural {
+            buf.append_to_ending("те");
+        }
+
+        self.place_stress(stress_at, buf);
+
+        if self.flags.has_alternating_yo() {
+            self.apply_ye_yo_alternation(buf);
+        }
+    }
+
+    fn inflect_present(self, info: ConjInfo, buf: &mut InflectionBuf) {
+        // Determine the stress position
+        buf.stress = self.stress.present.pos(info.number, info.person);
+
+        // Transform the infinitive stem into the present tense stem
+        self.form_present_stem(info, buf);
+
+        // Append the first or second conjugation's ending
+        let soft = self.is_soft_present_stem(buf.stem());
+        let stressed = buf.is_ending_stressed();
+        buf.append_to_ending(self.find_present_ending(info.number, info.person, soft, stressed));
+    }
+
+    fn inflect_past(self, info: ConjInfo, buf: &mut InflectionBuf) {
+        // Determine the stress position
+        buf.stress = self.stress.past.pos(info.number, info.gender);
+
+        // Transform the infinitive stem into the past tense stem, and append the -л- suffix.
+        // Stems ending with a consonant don't have it in masculine form (нёс, пёк, тёр, сох).
+        let has_suffix = self.form_past_stem(buf);
+        if has_suffix || info.is_plural() || info.gender != Gender::Masculine {
+            buf.append_to_stem("л");
+        }
+
+        buf.append_to_ending(Self::find_past_ending(info.number, info.gender));
+    }
+
+    pub(super) fn form_present_stem(self, info: ConjInfo, buf: &mut InflectionBuf) {
+        use {ConjugationClass::*, Utf8Letter::*};
+
+        let is_first_singular = info.is_singular() && info.is_first();
+        let is_third_plural = info.is_plural() && info.is_third();
+
+        match self.class {
+            // делать - делаю, краснеть - краснею, терять - теряю
+            Class1 => {},
+            // советовать - советую, воевать - воюю, танцевать - танцую
+            Class2 => {
+                let is_yeva = matches!(buf.stem(), [.., Е, В, А]);
+                // Shrink by 3 chars, removing '[ое]ва'
+                buf.shrink_stem_by(3);
+
+                let after_sibilant = buf.stem().last().is_some_and(|x| x.is_sibilant());
+                buf.append_to_stem(if is_yeva && !after_sibilant { "ю" } else { "у" });
+            },
+            // крикнуть - крикну, сохнуть - сохну
+            Class3 => buf.shrink_stem_by(1),
+            // строить - строю, просить - прошу/просишь, смотреть - смотрю, спать - сплю/спишь
+            Class4 | Class5 => {
+                buf.shrink_stem_by(1);
+                // Only the first person singular form has a consonant alternation
+                if is_first_singular {
+                    Self::alternate_consonant(buf);
+                }
+            },
+            // писать - пишу, сеять - сею, сыпать - сыплю; брать - беру, ждать - жду
+            Class6 => {
+                buf.shrink_stem_by(1);
+                if self.flags.has_circle() {
+                    Self::apply_unique_alternation(buf);
+                } else {
+                    Self::alternate_consonant(buf);
+                }
+            },
+            // нести - несу, вести (-д-) - веду, грести (-б-) - гребу
+            Class7 => {
+                if let Some(consonant) = self.stem_consonant
+                    && let Some(last) = buf.stem_mut().last_mut()
+                {
+                    *last = consonant;
+                }
+            },
+            // печь (-к-) - пеку/печёшь, мочь (-г-) - могу/можешь
+            Class8 => {
+                buf.append_to_stem(self.stem_consonant.unwrap_or(К).as_str());
+                // The consonant alternates in all forms except 1st person sg. and 3rd person pl.
+                if !is_first_singular && !is_third_plural {
+                    match buf.stem_mut().last_mut() {
+                        Some(ch @ К) => *ch = Ч,
+                        Some(ch @ Г) => *ch = Ж,
+                        _ => {},
+                    };
+                }
+            },
+            // тереть - тру, умереть - умру
+            Class9 => {
+                // Shrink by 1 char, removing the last 'е', and then remove the other 'е'
+                buf.shrink_stem_by(1);
+                buf.remove_pre_last_stem_char();
+            },
+            // колоть - колю, бороться - борюсь
+            Class10 => buf.shrink_stem_by(1),
+            // бить - бью, пить - пью
+            Class11 => {
+                if let Some(last) = buf.stem_mut().last_mut() {
+                    *last = Ь;
+                }
+            },
+            // мыть - мою, дуть - дую, гнить - гнию; петь - пою
+            Class12 => match buf.stem_mut().last_mut() {
+                Some(ch @ Ы) => *ch = О,
+                Some(ch @ Е) if self.flags.has_circle() => *ch = О,
+                _ => {},
+            },
+            // давать - даю, вставать - встаю
+            Class13 => buf.shrink_stem_by(2),
+            // жать (-м-) - жму, мять (-н-) - мну, начать (-н-) - начну
+            Class14 => {
+                buf.shrink_stem_by(1);
+                buf.append_to_stem(self.stem_consonant.unwrap_or(Н).as_str());
+            },
+            // стать - стану, деть - дену
+            Class15 => buf.append_to_stem("н"),
+            // жить - живу, плыть - плыву
+            Class16 => buf.append_to_stem("в"),
+        };
+    }
+
+    pub(super) fn form_past_stem(self, buf: &mut InflectionBuf) -> bool {
+        use {ConjugationClass::*, Utf8Letter::*};
+
+        match self.class {
+            // сохнуть - сох, сохла
+            Class3 if self.flags.has_circle() => {
+                // Shrink by 2 chars, removing 'ну'
+                buf.shrink_stem_by(2);
+                false
+            },
+            Class7 => match self.stem_consonant {
+                // вести (-д-) - вёл, вела; мести (-т-) - мёл, мела
+                Some(Д | Т) => {
+                    buf.shrink_stem_by(1);
+                    true
+                },
+                // грести (-б-) - грёб, гребла
+                Some(consonant) => {
+                    if let Some(last) = buf.stem_mut().last_mut() {
+                        *last = consonant;
+                    }
+                    false
+                },
+                // нести - нёс, несла; лезть - лез, лезла
+                None => false,
+            },
+            // печь (-к-) - пёк, пекла; мочь (-г-) - мог, могла
+            Class8 => {
+                buf.append_to_stem(self.stem_consonant.unwrap_or(К).as_str());
+                false
+            },
+            // тереть - тёр, тёрла
+            Class9 => {
+                buf.shrink_stem_by(1);
+                false
+            },
+            _ => true,
+        }
+    }
+
+    fn is_soft_present_stem(self, stem: &[Utf8Letter]) -> bool {
+        use {ConjugationClass::*, Utf8Letter::*};
+
+        match stem.last() {
+            // Vowels and 'ь' are always followed by 'ю' (делаю, бью)
+            Some(x) if x.is_vowel() || *x == Ь => true,
+            // Hissing consonants are always followed by 'у' (пишу, держу, плачут, учат)
+            Some(x) if x.is_hissing() => false,
+            // The epenthetic 'л' in class 6 is soft (сыплю, колеблют)
+            Some(Л) if self.class == Class6 => true,
+            // Second conjugation and class 10 have soft consonants (говорю, колют)
+            Some(_) => self.class.is_second_conjugation() || self.class == Class10,
+            None => false,
+        }
+    }
+
+    pub(super) fn alternate_consonant(buf: &mut InflectionBuf) {
+        use Utf8Letter::*;
+
+        match buf.stem_mut() {
+            // чистить - чищу, свистать - свищу, искать - ищу
+            [.., s @ С, Т | К] => {
+                *s = Щ;
+                buf.shrink_stem_by(1);
+            },
+            // просить - прошу, махать - машу
+            [.., ch @ (С | Х)] => *ch = Ш,
+            // возить - вожу, двигать - движу, ходить - хожу
+            [.., ch @ (З | Г | Д)] => *ch = Ж,
+            // плакать - плачу, платить - плачу
+            [.., ch @ (К | Т)] => *ch = Ч,
+            // любить - люблю, сыпать - сыплю, дремать - дремлю
+            [.., Б | П | В | Ф | М] => buf.append_to_stem("л"),
+            _ => {},
+        };
+    }
+
+    fn apply_unique_alternation(buf: &mut InflectionBuf) {
+        use Utf8Letter::*;
+
+        match buf.stem() {
+            // брать - беру, драть - деру
+            [.., Б | Д, Р] => buf.insert_between_last_two_stem_chars("е"),
+            // звать - зову
+            [.., З, В] => buf.insert_between_last_two_stem_chars("о"),
+            // ждать - жду, рвать - рву, ржать - ржу
+            _ => {},
+        };
+    }
+
+    pub(super) fn place_stress(self, stress_at: usize, buf: &mut InflectionBuf) {
+        let (stem, ending) = buf.stem_and_ending();
+
+        buf.stress_at = if buf.is_ending_stressed()
+            && let Some(pos) = ending.iter().position(|x| x.is_vowel())
+        {
+            // Stress the first vowel of the ending
+            stem.len() + pos + 1
+        } else if stress_at > 0 && stress_at <= stem.len() && stem[stress_at - 1].is_vowel() {
+            // Keep the stress on the same letter, if it's still in the stem
+            stress_at
+        } else {
+            // The stressed letter was removed or replaced, stress the last vowel in the stem.
+            // E.g. писа́ть - пи́шешь, рисова́ть - рису́ю, коло́ть - ко́лешь.
+            stem.iter().rposition(|x| x.is_vowel()).map_or(0, |x| x + 1)
+        };
+    }
+
+    pub(super) fn apply_ye_yo_alternation(self, buf: &mut InflectionBuf) {
+        let (stress_at, stem_len) = (buf.stress_at, buf.stem_len);
+        let stem = buf.stem_mut();
+
+        if stress_at > 0 && stress_at <= stem_len {
+            // If stress falls on the stem, stress 'е' into 'ё' (нёс, вёл, тёр)
+            if let ye @ Utf8Letter::Е = &mut stem[stress_at - 1] {
+                *ye = Utf8Letter::Ё;
+            }
+        } else if let Some(yo) = stem.iter_mut().find(|x| **x == Utf8Letter::Ё) {
+            // If stress falls on the ending, unstress 'ё' in the stem into 'е'
+            *yo = Utf8Letter::Е;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Number, Person},
+        verb::{Aspect, Transitivity},
+        word::Accent,
+    };
+
+    fn conj(word: &str, conj: &str) -> [String; 2] {
+        let info = VerbInfo {
+            aspect: Aspect::Imperfective,
+            transitivity: Transitivity::Transitive,
+            reflexive: false,
+            conjugation: Some(conj.parse().unwrap()),
+        };
+        let verb = Verb::from_word(word, info).unwrap();
+
+        let fmt =
+            |word: WordBuf| word.display().accent(Accent::explicit(Accent::ACUTE)).to_string();
+
+        let present = Number::VALUES.into_iter().flat_map(|number| {
+            Person::VALUES.map(|person| {
+                let tense = Tense::Present;
+                fmt(verb.inflect(ConjInfo { tense, number, person, gender: Gender::Masculine }))
+            })
+        });
+        let past = [
+            (Number::Singular, Gender::Masculine),
+            (Number::Singular, Gender::Feminine),
+            (Number::Singular, Gender::Neuter),
+            (Number::Plural, Gender::Masculine),
+        ]
+        .map(|(number, gender)| {
+            let tense = Tense::Past;
+            fmt(verb.inflect(ConjInfo { tense, number, gender, person: Person::Third }))
+        });
+
+        [present.collect::<Vec<_>>().join(", "), past.join(", ")]
+    }
+    fn imp(word: &str, conj: &str) -> Option<String> {
+        let info = VerbInfo {
+            aspect: Aspect::Imperfective,
+            transitivity: Transitivity::Transitive,
+            reflexive: false,
+            conjugation: Some(conj.parse().unwrap()),
+        };
+        imp_verb(&Verb::from_word(word, info).unwrap(), false)
+    }
+    fn imp_verb(verb: &Verb, force: bool) -> Option<String> {
+        let fmt = |number| {
+            let word = verb.inflect_imperative(number, force)?;
+            Some(word.display().accent(Accent::explicit(Accent::ACUTE)).to_string())
+        };
+        Some(fmt(Number::Singular)? + ", " + &fmt(Number::Plural)?)
+    }
+
+    #[test]
+    fn first_conjugation() {
+        // Class 1
+        assert_eq!(conj("чита́ть", "1a"), [
+            "чита́ю, чита́ешь, чита́ет, чита́ем, чита́ете, чита́ют",
+            "чита́л, чита́ла, чита́ло, чита́ли",
+        ]);
+        assert_eq!(conj("красне́ть", "1a"), [
+            "красне́ю, красне́ешь, красне́ет, красне́ем, красне́ете, красне́ют",
+            "красне́л, красне́ла, красне́ло, красне́ли",
+        ]);
+
+        // Class 2
+        assert_eq!(conj("рисова́ть", "2a"), [
+            "рису́ю, рису́ешь, рису́ет, рису́ем, рису́ете, рису́ют",
+            "рисова́л, рисова́ла, рисова́ло, рисова́ли",
+        ]);
+        assert_eq!(conj("воева́ть", "2a"), [
+            "вою́ю, вою́ешь, вою́ет, вою́ем, вою́ете, вою́ют",
+            "воева́л, воева́ла, воева́ло, воева́ли",
+        ]);
+        assert_eq!(conj("танцева́ть", "2a"), [
+            "танцу́ю, танцу́ешь, танцу́ет, танцу́ем, танцу́ете, танцу́ют",
+            "танцева́л, танцева́ла, танцева́ло, танцева́ли",
+        ]);
+        assert_eq!(conj("кова́ть", "2b"), [
+            "кую́, куё́шь, куё́т, куё́м, куё́те, кую́т",
+            "кова́л, кова́ла, кова́ло, кова́ли",
+        ]);
+
+        // Class 3
+        assert_eq!(conj("кри́кнуть", "3a"), [
+            "кри́кну, кри́кнешь, кри́кнет, кри́кнем, кри́кнете, кри́кнут",
+            "кри́кнул, кри́кнула, кри́кнуло, кри́кнули",
+        ]);
+        assert_eq!(conj("толкну́ть", "3b"), [
+            "толкну́, толкнё́шь, толкнё́т, толкнё́м, толкнё́те, толкну́т",
+            "толкну́л, толкну́ла, толкну́ло, толкну́ли",
+        ]);
+        assert_eq!(conj("со́хнуть", "3°a"), [
+            "со́хну, со́хнешь, со́хнет, со́хнем, со́хнете, со́хнут",
+            "со́х, со́хла, со́хло, со́хли",
+        ]);
+
+        // Class 6
+        assert_eq!(conj("писа́ть", "6c"), [
+            "пишу́, пи́шешь, пи́шет, пи́шем, пи́шете, пи́шут",
+            "писа́л, писа́ла, писа́ло, писа́ли",
+        ]);
+        assert_eq!(conj("се́ять", "6a"), [
+            "се́ю, се́ешь, се́ет, се́ем, се́ете, се́ют",
+            "се́ял, се́яла, се́яло, се́яли",
+        ]);
+        assert_eq!(conj("пла́кать", "6a"), [
+            "пла́чу, пла́чешь, пла́чет, пла́чем, пла́чете, пла́чут",
+            "пла́кал, пла́кала, пла́кало, пла́кали",
+        ]);
+        assert_eq!(conj("иска́ть", "6c"), [
+            "ищу́, и́щешь, и́щет, и́щем, и́щете, и́щут",
+            "иска́л, иска́ла, иска́ло, иска́ли",
+        ]);
+        assert_eq!(conj("сы́пать", "6a"), [
+            "сы́плю, сы́плешь, сы́плет, сы́плем, сы́плете, сы́плют",
+            "сы́пал, сы́пала, сы́пало, сы́пали",
+        ]);
+        assert_eq!(conj("бра́ть", "6°b/c"), [
+            "беру́, берё́шь, берё́т, берё́м, берё́те, беру́т",
+            "бра́л, брала́, бра́ло, бра́ли",
+        ]);
+        assert_eq!(conj("зва́ть", "6°b/c"), [
+            "зову́, зовё́шь, зовё́т, зовё́м, зовё́те, зову́т",
+            "зва́л, звала́, зва́ло, зва́ли",
+        ]);
+        assert_eq!(conj("жда́ть", "6°b/c"), [
+            "жду́, ждё́шь, ждё́т, ждё́м, ждё́те, жду́т",
+            "жда́л, ждала́, жда́ло, жда́ли",
+        ]);
+    }
+
+    #[test]
+    fn second_conjugation() {
+        // Class 4
+        assert_eq!(conj("стро́ить", "4a"), [
+            "стро́ю, стро́ишь, стро́ит, стро́им, стро́ите, стро́ят",
+            "стро́ил, стро́ила, стро́ило, стро́или",
+        ]);
+        assert_eq!(conj("говори́ть", "4b"), [
+            "говорю́, говори́шь, говори́т, говори́м, говори́те, говоря́т",
+            "говори́л, говори́ла, говори́ло, говори́ли",
+        ]);
+        assert_eq!(conj("проси́ть", "4c"), [
+            "прошу́, про́сишь, про́сит, про́сим, про́сите, про́сят",
+            "проси́л, проси́ла, проси́ло, проси́ли",
+        ]);
+        assert_eq!(conj("люби́ть", "4c"), [
+            "люблю́, лю́бишь, лю́бит, лю́бим, лю́бите, лю́бят",
+            "люби́л, люби́ла, люби́ло, люби́ли",
+        ]);
+        assert_eq!(conj("учи́ть", "4c"), [
+            "учу́, у́чишь, у́чит, у́чим, у́чите, у́чат",
+            "учи́л, учи́ла, учи́ло, учи́ли",
+        ]);
+        assert_eq!(conj("чи́стить", "4a"), [
+            "чи́щу, чи́стишь, чи́стит, чи́стим, чи́стите, чи́стят",
+            "чи́стил, чи́стила, чи́стило, чи́стили",
+        ]);
+
+        // Class 5
+        assert_eq!(conj("смотре́ть", "5c"), [
+            "смотрю́, смо́тришь, смо́трит, смо́трим, смо́трите, смо́трят",
+            "смотре́л, смотре́ла, смотре́ло, смотре́ли",
+        ]);
+        assert_eq!(conj("ви́деть", "5a"), [
+            "ви́жу, ви́дишь, ви́дит, ви́дим, ви́дите, ви́дят",
+            "ви́дел, ви́дела, ви́дело, ви́дели",
+        ]);
+        assert_eq!(conj("спа́ть", "5b/c"), [
+            "сплю́, спи́шь, спи́т, спи́м, спи́те, спя́т",
+            "спа́л, спала́, спа́ло, спа́ли",
+        ]);
+        assert_eq!(conj("держа́ть", "5c"), [
+            "держу́, де́ржишь, де́ржит, де́ржим, де́ржите, де́ржат",
+            "держа́л, держа́ла, держа́ло, держа́ли",
+        ]);
+        assert_eq!(conj("стоя́ть", "5b"), [
+            "стою́, стои́шь, стои́т, стои́м, стои́те, стоя́т",
+            "стоя́л, стоя́ла, стоя́ло, стоя́ли",
+        ]);
+    }
+
+    #[test]
+    fn consonant_stems() {
+        // Class 7
+        assert_eq!(conj("нести́", "7b/b, ё"), [
+            "несу́, несё́шь, несё́т, несё́м, несё́те, несу́т",
+            "нё́с, несла́, несло́, несли́",
+        ]);
+        assert_eq!(conj("вести́", "7b/b(-д-), ё"), [
+            "веду́, ведё́шь, ведё́т, ведё́м, ведё́те, веду́т",
+            "вё́л, вела́, вело́, вели́",
+        ]);
+        assert_eq!(conj("грести́", "7b/b(-б-), ё"), [
+            "гребу́, гребё́шь, гребё́т, гребё́м, гребё́те, гребу́т",
+            "грё́б, гребла́, гребло́, гребли́",
+        ]);
+        assert_eq!(conj("ле́зть", "7a"), [
+            "ле́зу, ле́зешь, ле́зет, ле́зем, ле́зете, ле́зут",
+            "ле́з, ле́зла, ле́зло, ле́зли",
+        ]);
+
+        // Class 8
+        assert_eq!(conj("печь", "8b/b(-к-), ё"), [
+            "пеку́, печё́шь, печё́т, печё́м, печё́те, пеку́т",
+            "пё́к, пекла́, пекло́, пекли́",
+        ]);
+        assert_eq!(conj("мочь", "8c/b(-г-)"), [
+            "могу́, мо́жешь, мо́жет, мо́жем, мо́жете, мо́гут",
+            "мо́г, могла́, могло́, могли́",
+        ]);
+
+        // Class 9
+        assert_eq!(conj("тере́ть", "9b, ё"), [
+            "тру́, трё́шь, трё́т, трё́м, трё́те, тру́т",
+            "тё́р, тё́рла, тё́рло, тё́рли",
+        ]);
+    }
+
+    #[test]
+    fn other_classes() {
+        // Class 10
+        assert_eq!(conj("коло́ть", "10c"), [
+            "колю́, ко́лешь, ко́лет, ко́лем, ко́лете, ко́лют",
+            "коло́л, коло́ла, коло́ло, коло́ли",
+        ]);
+
+        // Class 11
+        assert_eq!(conj("би́ть", "11b"), [
+            "бью́, бьё́шь, бьё́т, бьё́м, бьё́те, бью́т",
+            "би́л, би́ла, би́ло, би́ли",
+        ]);
+
+        // Class 12
+        assert_eq!(conj("мы́ть", "12a"), [
+            "мо́ю, мо́ешь, мо́ет, мо́ем, мо́ете, мо́ют",
+            "мы́л, мы́ла, мы́ло, мы́ли",
+        ]);
+        assert_eq!(conj("пе́ть", "12°b"), [
+            "пою́, поё́шь, поё́т, поё́м, поё́те, пою́т",
+            "пе́л, пе́ла, пе́ло, пе́ли",
+        ]);
+
+        // Class 13
+        assert_eq!(conj("дава́ть", "13b"), [
+            "даю́, даё́шь, даё́т, даё́м, даё́те, даю́т",
+            "дава́л, дава́ла, дава́ло, дава́ли",
+        ]);
+
+        // Class 14
+        assert_eq!(conj("жа́ть", "14b(-м-)"), [
+            "жму́, жмё́шь, жмё́т, жмё́м, жмё́те, жму́т",
+            "жа́л, жа́ла, жа́ло, жа́ли",
+        ]);
+        assert_eq!(conj("мя́ть", "14b(-н-)"), [
+            "мну́, мнё́шь, мнё́т, мнё́м, мнё́те, мну́т",
+            "мя́л, мя́ла, мя́ло, мя́ли",
+        ]);
+
+        // Class 15
+        assert_eq!(conj("ста́ть", "15a"), [
+            "ста́ну, ста́нешь, ста́нет, ста́нем, ста́нете, ста́нут",
+            "ста́л, ста́ла, ста́ло, ста́ли",
+        ]);
+
+        // Class 16
+        assert_eq!(conj("жи́ть", "16b/c"), [
+            "живу́, живё́шь, живё́т, живё́м, живё́те, живу́т",
+            "жи́л, жила́, жи́ло, жи́ли",
+        ]);
+    }
+
+    #[test]
+    fn imperative() {
+        let assert_imp = |word, conj, expected: &str| {
+            assert_eq!(imp(word, conj).as_deref(), Some(expected));
+        };
+
+        // Vowel stems
+        assert_imp("чита́ть", "1a", "чита́й, чита́йте");
+        assert_imp("рисова́ть", "2a", "рису́й, рису́йте");
+        assert_imp("кова́ть", "2b", "ку́й, ку́йте");
+        assert_imp("стро́ить", "4a", "стро́й, стро́йте");
+        assert_imp("стоя́ть", "5b", "сто́й, сто́йте");
+        assert_imp("се́ять", "6a", "се́й, се́йте");
+        assert_imp("би́ть", "11b", "бе́й, бе́йте");
+        assert_imp("мы́ть", "12a", "мо́й, мо́йте");
+        assert_imp("пе́ть", "12°b", "по́й, по́йте");
+        assert_imp("дава́ть", "13b", "дава́й, дава́йте");
+
+        // Stressed endings
+        assert_imp("писа́ть", "6c", "пиши́, пиши́те");
+        assert_imp("бра́ть", "6°b/c", "бери́, бери́те");
+        assert_imp("говори́ть", "4b", "говори́, говори́те");
+        assert_imp("люби́ть", "4c", "люби́, люби́те");
+        assert_imp("спа́ть", "5b/c", "спи́, спи́те");
+        assert_imp("нести́", "7b/b, ё", "неси́, неси́те");
+        assert_imp("печь", "8b/b(-к-), ё", "пеки́, пеки́те");
+        assert_imp("тере́ть", "9b, ё", "три́, три́те");
+        assert_imp("коло́ть", "10c", "коли́, коли́те");
+        assert_imp("жа́ть", "14b(-м-)", "жми́, жми́те");
+        assert_imp("жи́ть", "16b/c", "живи́, живи́те");
+
+        // Stressed stems
+        assert_imp("кри́кнуть", "3a", "кри́кни, кри́кните");
+        assert_imp("чи́стить", "4a", "чи́сти, чи́стите");
+        assert_imp("ве́рить", "4a", "ве́рь, ве́рьте");
+        assert_imp("пла́кать", "6a", "пла́чь, пла́чьте");
+        assert_imp("сы́пать", "6a", "сы́пь, сы́пьте");
+        assert_imp("ле́зть", "7a", "ле́зь, ле́зьте");
+        assert_imp("ста́ть", "15a", "ста́нь, ста́ньте");
+
+        // Difficult imperative (⑨)
+        assert_eq!(imp("мо́чь", "8c/b(-г-)⑨"), None);
+
+        let info = VerbInfo {
+            aspect: Aspect::Imperfective,
+            transitivity: Transitivity::Intransitive,
+            reflexive: false,
+            conjugation: Some("8c/b(-г-)⑨".parse().unwrap()),
+        };
+        let verb = Verb::from_word("мо́чь", info).unwrap();
+        assert_eq!(imp_verb(&verb, true).as_deref(), Some("моги́, моги́те"));
+
+        // лечь has an irregular present tense stem, ляг-
+        let info = VerbInfo {
+            aspect: Aspect::Perfective,
+            transitivity: Transitivity::Intransitive,
+            reflexive: false,
+            conjugation: Some("8a/b(-г-)".parse().unwrap()),
+        };
+        let verb = Verb::from_stem("ля́".parse().unwrap(), info);
+        assert_eq!(imp_verb(&verb, false).as_deref(), Some("ля́г, ля́гте"));
+    }
+
+    #[test]
+    fn reflexive() {
+        assert_eq!(conj("учи́ться", "4c"), [
+            "учу́сь, у́чишься, у́чится, у́чимся, у́читесь, у́чатся",
+            "учи́лся, учи́лась, учи́лось, учи́лись",
+        ]);
+        assert_eq!(conj("боя́ться", "5b"), [
+            "бою́сь, бои́шься, бои́тся, бои́мся, бои́тесь, боя́тся",
+            "боя́лся, боя́лась, боя́лось, боя́лись",
+        ]);
+        assert_eq!(conj("мы́ться", "12a"), [
+            "мо́юсь, мо́ешься, мо́ется, мо́емся, мо́етесь, мо́ются",
+            "мы́лся, мы́лась, мы́лось, мы́лись",
+        ]);
+        assert_eq!(conj("нести́сь", "7b/b, ё"), [
+            "несу́сь, несё́шься, несё́тся, несё́мся, несё́тесь, несу́тся",
+            "нё́сся, несла́сь, несло́сь, несли́сь",
+        ]);
+
+        assert_eq!(imp("учи́ться", "4c").as_deref(), Some("учи́сь, учи́тесь"));
+        assert_eq!(imp("мы́ться", "12a").as_deref(), Some("мо́йся, мо́йтесь"));
+        assert_eq!(imp("ста́виться", "4a").as_deref(), Some("ста́вься, ста́вьтесь"));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    conjugation::{CONJUGATION_MAX_LEN, Conjugation},
+    util::UnsafeBuf,
+    verb::{Aspect, Transitivity, VerbInfo},
+};
+
+/// The maximum byte length of a formatted [`VerbInfo`].
+///
+/// Longest form: св-нсв-ся 14°*c′/c″(-н-)⑨, ё (44 bytes, 29 chars)
+/// Max additions: св-нсв-ся (+17 bytes, +10 chars)
+pub const VERB_INFO_MAX_LEN: usize = CONJUGATION_MAX_LEN + 17;
+
+impl VerbInfo {
+    /// Formats this verb info as UTF-8 into the provided byte buffer, and then returns a subslice
+    /// of the buffer that contains the encoded string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::verb::{VERB_INFO_MAX_LEN, VerbInfo};
+    ///
+    /// let info: VerbInfo = "св-нп 5a".parse().unwrap();
+    /// let mut buf = [0; VERB_INFO_MAX_LEN];
+    /// assert_eq!(info.fmt_to(&mut buf), "св-нп 5a");
+    /// ```
+    pub const fn fmt_to<'a>(&self, dst: &'a mut [u8; VERB_INFO_MAX_LEN]) -> &'a mut str {
+        let mut dst = UnsafeBuf::new(dst);
+
+        dst.push_str(match self.aspect {
+            Aspect::Imperfective => "нсв",
+            Aspect::Perfective => "св",
+            Aspect::Biaspectual => "св-нсв",
+        });
+
+        // Reflexive verbs are always intransitive, so 'нп' is implied by 'ся'
+        if self.reflexive {
+            dst.push_str("-ся");
+        } else if self.transitivity == Transitivity::Intransitive {
+            dst.push_str("-нп");
+        }
+
+        // Space between aspect/transitivity and conjugation
+        dst.push(' ');
+
+        if let Some(conjugation) = self.conjugation {
+            dst.push_fmt2(conjugation, Conjugation::fmt_to);
+        } else {
+            dst.push('0');
+        }
+
+        dst.finish()
+    }
+}
+
+impl std::fmt::Display for VerbInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.fmt_to(&mut [0; _]).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fmt() {
+        let assert_round_trip = |s: &str| assert_eq!(s.parse::<VerbInfo>().unwrap().to_string(), s);
+
+        assert_round_trip("нсв 14b/c″");
+        assert_round_trip("св-нп 5a");
+        assert_round_trip("нсв 6°b/c, ё");
+        assert_round_trip("нсв-ся 4c");
+        assert_round_trip("св-нсв 2a");
+        assert_round_trip("нсв-нп 7b/b(-д-), ё");
+        assert_round_trip("нсв-нп 8c/b(-г-)⑨");
+        assert_round_trip("св-нсв-ся 14°*c′/c″(-н-)⑨, ё");
+        assert_round_trip("св 0");
+    }
+}
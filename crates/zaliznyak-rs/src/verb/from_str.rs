@@ -0,0 +1,159 @@
+use crate::{
+    conjugation::{Conjugation, ParseConjugationError},
+    util::{PartialFromStr, UnsafeParser},
+    verb::{Aspect, Transitivity, VerbInfo},
+};
+use thiserror::Error;
+
+/// Error type for parsing [`VerbInfo`] from a string.
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum ParseVerbInfoError {
+    /// The string doesn't start with a valid aspect: `нсв`, `св` or `св-нсв`.
+    #[error("invalid characters in place of aspect")]
+    InvalidAspect,
+    /// Error parsing the verb's conjugation.
+    #[error("error parsing conjugation: {0}")]
+    InvalidConjugation(ParseConjugationError),
+    /// Invalid format.
+    #[error("invalid format")]
+    Invalid,
+}
+
+impl const PartialFromStr for VerbInfo {
+    fn partial_from_str(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        // 'нсв', 'св' or 'св-нсв'
+        let aspect = if parser.skip_str("нсв") {
+            Aspect::Imperfective
+        } else if parser.skip_str("св") {
+            if parser.skip_str("-нсв") { Aspect::Biaspectual } else { Aspect::Perfective }
+        } else {
+            return Err(Self::Err::InvalidAspect);
+        };
+
+        // 'нп' marks intransitive verbs, and 'ся' marks reflexive ones (which are always intransitive)
+        let mut transitivity = Transitivity::Transitive;
+        let mut reflexive = false;
+
+        if parser.skip_str("-нп") {
+            transitivity = Transitivity::Intransitive;
+        } else if parser.skip_str("-ся") {
+            transitivity = Transitivity::Intransitive;
+            reflexive = true;
+        }
+
+        // Expect a space between aspect/transitivity and conjugation
+        if !parser.skip(' ') {
+            return Err(Self::Err::Invalid);
+        }
+
+        let conjugation = if parser.skip('0') {
+            None
+        } else {
+            match Conjugation::partial_from_str(parser) {
+                Ok(conj) => Some(conj),
+                Err(err) => return Err(Self::Err::InvalidConjugation(err)),
+            }
+        };
+
+        Ok(Self { aspect, transitivity, reflexive, conjugation })
+    }
+}
+
+impl const std::str::FromStr for VerbInfo {
+    type Err = ParseVerbInfoError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_or_err(s, Self::Err::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        conjugation::{ConjugationClass::*, ConjugationFlags as CF},
+        stress::VerbStress as S,
+        word::Utf8Letter,
+    };
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "нсв 14b/c″(-н-)".parse(),
+            Ok(VerbInfo {
+                aspect: Aspect::Imperfective,
+                transitivity: Transitivity::Transitive,
+                reflexive: false,
+                conjugation: Some(Conjugation {
+                    class: Class14,
+                    stress: S::B_Cpp,
+                    flags: CF::empty(),
+                    stem_consonant: Some(Utf8Letter::Н),
+                }),
+            }),
+        );
+        assert_eq!(
+            "св-нп 5a".parse(),
+            Ok(VerbInfo {
+                aspect: Aspect::Perfective,
+                transitivity: Transitivity::Intransitive,
+                reflexive: false,
+                conjugation: Some(Conjugation {
+                    class: Class5,
+                    stress: S::A_A,
+                    flags: CF::empty(),
+                    stem_consonant: None,
+                }),
+            }),
+        );
+        assert_eq!(
+            "нсв 6°b/c, ё".parse(),
+            Ok(VerbInfo {
+                aspect: Aspect::Imperfective,
+                transitivity: Transitivity::Transitive,
+                reflexive: false,
+                conjugation: Some(Conjugation {
+                    class: Class6,
+                    stress: S::B_C,
+                    flags: CF::CIRCLE | CF::ALTERNATING_YO,
+                    stem_consonant: None,
+                }),
+            }),
+        );
+        assert_eq!(
+            "св-нсв-ся 4c".parse(),
+            Ok(VerbInfo {
+                aspect: Aspect::Biaspectual,
+                transitivity: Transitivity::Intransitive,
+                reflexive: true,
+                conjugation: Some(Conjugation {
+                    class: Class4,
+                    stress: S::C_A,
+                    flags: CF::empty(),
+                    stem_consonant: None,
+                }),
+            }),
+        );
+        assert_eq!(
+            "нсв-нп 0".parse(),
+            Ok(VerbInfo {
+                aspect: Aspect::Imperfective,
+                transitivity: Transitivity::Intransitive,
+                reflexive: false,
+                conjugation: None,
+            }),
+        );
+
+        let assert_err = |s: &str, err| assert_eq!(s.parse::<VerbInfo>(), Err(err));
+
+        assert_err("", ParseVerbInfoError::InvalidAspect);
+        assert_err("мо 1a", ParseVerbInfoError::InvalidAspect);
+        assert_err("нсв", ParseVerbInfoError::Invalid);
+        assert_err("нсв-пп 1a", ParseVerbInfoError::Invalid);
+        assert_err("нсв 1a ", ParseVerbInfoError::Invalid);
+        assert_err(
+            "нсв 17a",
+            ParseVerbInfoError::InvalidConjugation(ParseConjugationError::InvalidClass),
+        );
+    }
+}
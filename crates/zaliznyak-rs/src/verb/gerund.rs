@@ -0,0 +1,224 @@
+use crate::{
+    categories::{ConjInfo, Gender, Number, Person, Tense},
+    conjugation::{Conjugation, ConjugationClass},
+    util::InflectionBuf,
+    verb::{Aspect, GerundKind, Verb, VerbInfo, conjugation::append_reflexive_postfix},
+    word::{Utf8Letter, Word, WordBuf},
+};
+
+impl Verb {
+    /// Forms the verb's gerund of the specified kind.
+    ///
+    /// Returns `None` if the verb doesn't have such a gerund (present gerunds of perfective
+    /// verbs), or if it's difficult to form or rarely used and `force` is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::verb::{GerundKind, Verb};
+    ///
+    /// let verb = Verb::from_word("чита́ть", "нсв 1a".parse().unwrap()).unwrap();
+    /// assert_eq!(verb.gerund(GerundKind::Present, false).unwrap().as_str(), "читая");
+    ///
+    /// let verb = Verb::from_word("прочита́ть", "св 1a".parse().unwrap()).unwrap();
+    /// assert_eq!(verb.gerund(GerundKind::Present, false), None);
+    /// assert_eq!(verb.gerund(GerundKind::Past, false).unwrap().as_str(), "прочитав");
+    /// ```
+    pub fn gerund(&self, kind: GerundKind, force: bool) -> Option<WordBuf> {
+        self.info.gerund(self.stem.borrow(), kind, force)
+    }
+}
+
+impl VerbInfo {
+    /// Forms the gerund of a verb with the specified stem. See [`Verb::gerund`].
+    pub fn gerund(&self, stem: Word, kind: GerundKind, force: bool) -> Option<WordBuf> {
+        let conj = self.conjugation?;
+
+        let mut word = WordBuf::with_stem(stem, 8);
+        let mut buf = InflectionBuf::new(&mut word);
+
+        // Check if the gerund is missing, or if it's difficult to form and isn't forced
+        if !conj.inflect_gerund(kind, self.aspect, &mut buf).unwrap_or(force) {
+            return None;
+        }
+
+        if self.reflexive {
+            // Reflexive verbs always use the longer form: умыв - умывшись
+            if let Some(Utf8Letter::В) = buf.as_slice().last() {
+                buf.append_to_ending("ши");
+            }
+            append_reflexive_postfix(&mut buf);
+        }
+
+        buf.finish(&mut word);
+        Some(word)
+    }
+}
+
+impl Conjugation {
+    /// Forms the specified gerund, and returns whether it's used: `Some(true)` if it's used,
+    /// `Some(false)` if it's missing, and `None` if it's difficult to form or rarely used.
+    fn inflect_gerund(
+        self,
+        kind: GerundKind,
+        aspect: Aspect,
+        buf: &mut InflectionBuf,
+    ) -> Option<bool> {
+        use {ConjugationClass::*, GerundKind::*};
+
+        match kind {
+            Present => {
+                if aspect == Aspect::Perfective {
+                    return Some(false);
+                }
+                self.inflect_present_gerund(buf)
+            },
+            Past => {
+                match self.class {
+                    // нести - неся, привести - приведя
+                    Class7 => _ = self.inflect_present_gerund(buf),
+                    // печь - пёкши
+                    Class8 => {
+                        self.form_past_active_stem(buf);
+                        buf.append_to_ending("ши");
+                    },
+                    // прочитать - прочитав, крикнуть - крикнув, стереть - стерев
+                    _ => buf.append_to_ending("в"),
+                };
+                // Past gerunds of imperfective verbs are rarely used (читав)
+                if aspect == Aspect::Imperfective { None } else { Some(true) }
+            },
+            PastShi => {
+                // прочитать - прочитавши, принести - принёсши, стереть - стёрши
+                self.form_past_active_stem(buf);
+                buf.append_to_ending("ши");
+
+                if aspect == Aspect::Imperfective { None } else { Some(true) }
+            },
+        }
+    }
+
+    fn inflect_present_gerund(self, buf: &mut InflectionBuf) -> Option<bool> {
+        use ConjugationClass::*;
+
+        // Present gerunds of these classes are not used (*крикня, *могя, *тря, *бья, *жмя)
+        if let Class3 | Class8 | Class9 | Class11 | Class14 = self.class {
+            return Some(false);
+        }
+
+        // Remember the stressed letter of the infinitive stem
+        let stress_at = buf.stress_at;
+
+        // The present gerund is formed from the 3rd person plural present tense stem, and is
+        // stressed the same way as the 1st person singular present form. Except for class 13,
+        // that keeps the infinitive stem and its stress (давать - давая).
+        if self.class != Class13 {
+            buf.stress = self.stress.present.pos(Number::Singular, Person::First);
+
+            let (tense, gender) = (Tense::Present, Gender::Masculine);
+            let info = ConjInfo { tense, number: Number::Plural, person: Person::Third, gender };
+            self.form_present_stem(info, buf);
+        }
+
+        let is_used = match buf.stem() {
+            // читать - читая, сеять - сея, давать - давая
+            [.., x] if x.is_vowel() => Some(true),
+            [..] if self.class != Class6 => Some(true),
+            // брать - беря, звать - зовя
+            stem if self.flags.has_circle() && stem.iter().any(|x| x.is_vowel()) => Some(true),
+            // писать - пиша, плакать - плача, ждать - ждя
+            _ => None,
+        };
+
+        // After hissing consonants, 'я' becomes 'а' (держа, крича)
+        let hissing = buf.stem().last().is_some_and(|x| x.is_hissing());
+        buf.append_to_ending(if hissing { "а" } else { "я" });
+
+        self.place_stress(stress_at, buf);
+
+        if self.flags.has_alternating_yo() {
+            self.apply_ye_yo_alternation(buf);
+        }
+
+        is_used
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::Accent;
+
+    fn ger(word: &str, info: &str, kind: GerundKind, force: bool) -> Option<String> {
+        let verb = Verb::from_word(word, info.parse().unwrap()).unwrap();
+        let word = verb.gerund(kind, force)?;
+        Some(word.display().accent(Accent::explicit(Accent::ACUTE)).to_string())
+    }
+
+    #[test]
+    fn present() {
+        let assert_ger = |word, info, expected: Option<&str>| {
+            assert_eq!(ger(word, info, GerundKind::Present, false).as_deref(), expected);
+        };
+
+        assert_ger("чита́ть", "нсв 1a", Some("чита́я"));
+        assert_ger("рисова́ть", "нсв 2a", Some("рису́я"));
+        assert_ger("говори́ть", "нсв 4b", Some("говоря́"));
+        assert_ger("люби́ть", "нсв 4c", Some("любя́"));
+        assert_ger("держа́ть", "нсв 5c", Some("держа́"));
+        assert_ger("ви́деть", "нсв 5a", Some("ви́дя"));
+        assert_ger("се́ять", "нсв 6a", Some("се́я"));
+        assert_ger("бра́ть", "нсв 6°b/c", Some("беря́"));
+        assert_ger("нести́", "нсв 7b/b, ё", Some("неся́"));
+        assert_ger("коло́ть", "нсв 10c", Some("коля́"));
+        assert_ger("мы́ть", "нсв 12a", Some("мо́я"));
+        assert_ger("дава́ть", "нсв 13b", Some("дава́я"));
+        assert_ger("жи́ть", "нсв-нп 16b/c", Some("живя́"));
+
+        // Difficult present gerunds
+        assert_ger("писа́ть", "нсв 6c", None);
+        assert_ger("жда́ть", "нсв 6°b/c", None);
+        assert_eq!(ger("писа́ть", "нсв 6c", GerundKind::Present, true).as_deref(), Some("пиша́"));
+
+        // Missing present gerunds
+        assert_ger("печь", "нсв 8b/b(-к-), ё", None);
+        assert_ger("би́ть", "нсв 11b", None);
+        assert_eq!(ger("би́ть", "нсв 11b", GerundKind::Present, true), None);
+        assert_ger("прочита́ть", "св 1a", None);
+    }
+
+    #[test]
+    fn past() {
+        let assert_ger = |word, info, expected: [&str; 2]| {
+            let past = ger(word, info, GerundKind::Past, false);
+            let past_shi = ger(word, info, GerundKind::PastShi, false);
+            assert_eq!([past.as_deref(), past_shi.as_deref()], expected.map(Some));
+        };
+
+        assert_ger("прочита́ть", "св 1a", ["прочита́в", "прочита́вши"]);
+        assert_ger("кри́кнуть", "св-нп 3a", ["кри́кнув", "кри́кнувши"]);
+        assert_ger("купи́ть", "св 4c", ["купи́в", "купи́вши"]);
+        assert_ger("засо́хнуть", "св-нп 3°a", ["засо́хнув", "засо́хши"]);
+        assert_ger("принести́", "св 7b/b, ё", ["принеся́", "принё́сши"]);
+        assert_ger("привести́", "св 7b/b(-д-), ё", ["приведя́", "приве́дши"]);
+        assert_ger("испе́чь", "св 8b/b(-к-), ё", ["испё́кши", "испё́кши"]);
+        assert_ger("стере́ть", "св 9b, ё", ["стере́в", "стё́рши"]);
+
+        // Past gerunds of imperfective verbs are difficult
+        assert_eq!(ger("чита́ть", "нсв 1a", GerundKind::Past, false), None);
+        assert_eq!(ger("чита́ть", "нсв 1a", GerundKind::Past, true).as_deref(), Some("чита́в"));
+    }
+
+    #[test]
+    fn reflexive() {
+        let assert_ger = |word, info, kind, expected: &str| {
+            assert_eq!(ger(word, info, kind, false).as_deref(), Some(expected));
+        };
+
+        assert_ger("учи́ться", "нсв 4c", GerundKind::Present, "уча́сь");
+        assert_ger("боя́ться", "нсв 5b", GerundKind::Present, "боя́сь");
+        assert_ger("умы́ться", "св 12a", GerundKind::Past, "умы́вшись");
+        assert_ger("умы́ться", "св 12a", GerundKind::PastShi, "умы́вшись");
+        assert_ger("принести́сь", "св 7b/b, ё", GerundKind::Past, "принеся́сь");
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    conjugation::Conjugation,
+    word::{Utf8Letter, Word, WordBuf},
+};
+use thiserror::Error;
+
+mod conjugation;
+mod fmt;
+mod from_str;
+mod gerund;
+mod participle;
+
+pub use fmt::*;
+pub use from_str::*;
+
+/// A verb, consisting of its infinitive stem and grammatical info.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{ConjInfo, Gender, Number, Person, Tense},
+///     verb::Verb,
+/// };
+///
+/// let verb = Verb::from_word("писа́ть", "нсв 6c".parse().unwrap()).unwrap();
+///
+/// let info = ConjInfo {
+///     tense: Tense::Present,
+///     number: Number::Singular,
+///     person: Person::Second,
+///     gender: Gender::Masculine,
+/// };
+/// assert_eq!(verb.inflect(info).as_str(), "пишешь");
+/// ```
+#[derive(Debug, Clone, Eq, Hash)]
+#[derive_const(PartialEq)]
+pub struct Verb {
+    stem: WordBuf,
+    info: VerbInfo,
+}
+
+/// A verb's grammatical info: aspect, transitivity, reflexivity and conjugation.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::verb::{Aspect, Transitivity, VerbInfo};
+///
+/// let info: VerbInfo = "нсв-ся 4c".parse().unwrap();
+///
+/// assert_eq!(info.aspect, Aspect::Imperfective);
+/// assert_eq!(info.transitivity, Transitivity::Intransitive);
+/// assert!(info.reflexive);
+/// assert_eq!(info.to_string(), "нсв-ся 4c");
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub struct VerbInfo {
+    /// The verb's aspect.
+    pub aspect: Aspect,
+    /// The verb's transitivity.
+    pub transitivity: Transitivity,
+    /// Whether the verb is reflexive (учиться). Reflexive verbs are always intransitive.
+    pub reflexive: bool,
+    /// The verb's conjugation, or `None` if the verb doesn't conjugate regularly.
+    pub conjugation: Option<Conjugation>,
+}
+
+/// A verb's grammatical aspect.
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Default, Clone, PartialEq)]
+pub enum Aspect {
+    /// Imperfective aspect (делать). Несовершенный вид.
+    #[default]
+    Imperfective,
+    /// Perfective aspect (сделать). Совершенный вид.
+    Perfective,
+    /// Both imperfective and perfective aspects (казнить, жениться). Двувидовой глагол.
+    Biaspectual,
+}
+
+/// A verb's transitivity.
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Default, Clone, PartialEq)]
+pub enum Transitivity {
+    /// Transitive verb, that takes a direct object (читать книгу). Переходный глагол.
+    #[default]
+    Transitive,
+    /// Intransitive verb (спать). Непереходный глагол.
+    Intransitive,
+}
+
+/// A kind of participle. Participles decline like adjectives, see [`Verb::participle`].
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum ParticipleKind {
+    /// Present active participle (читающий). Действительное причастие настоящего времени.
+    PresentActive,
+    /// Past active participle (читавший). Действительное причастие прошедшего времени.
+    PastActive,
+    /// Present passive participle (читаемый). Страдательное причастие настоящего времени.
+    PresentPassive,
+    /// Past passive participle (прочитанный). Страдательное причастие прошедшего времени.
+    PastPassive,
+}
+
+/// A kind of gerund (verbal adverb), see [`Verb::gerund`].
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum GerundKind {
+    /// Present gerund (читая, неся). Деепричастие настоящего времени.
+    Present,
+    /// Past gerund (прочитав, принеся). Деепричастие прошедшего времени.
+    Past,
+    /// Past gerund with the -ши suffix (прочитавши, принёсши).
+    PastShi,
+}
+
+/// Error type for constructing a [`Verb`] from its infinitive.
+#[derive(Debug, Error, Copy, Eq, Hash)]
+#[derive_const(Clone, PartialEq)]
+pub enum NewVerbError {
+    /// The word is not a valid stressed word.
+    #[error("invalid stem")]
+    InvalidStem,
+    /// The word doesn't end in an infinitive ending: -ть, -ти or -чь.
+    #[error("not an infinitive")]
+    NotInfinitive,
+}
+
+impl Verb {
+    /// Constructs a verb from its infinitive stem, without the infinitive ending and the
+    /// reflexive postfix. Used for verbs with irregular infinitives (лечь --- ля́-).
+    pub const fn from_stem(stem: WordBuf, info: VerbInfo) -> Self {
+        Self { stem, info }
+    }
+
+    /// Constructs a verb from its infinitive, trimming the infinitive ending (-ть, -ти, -чь).
+    /// If the infinitive ends in the reflexive postfix (-ся, -сь), it's trimmed too, and the
+    /// verb is marked as reflexive and intransitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NewVerbError::InvalidStem`] if the word is not a valid stressed word, and
+    /// [`NewVerbError::NotInfinitive`] if it doesn't end in an infinitive ending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::verb::{NewVerbError, Verb};
+    ///
+    /// let verb = Verb::from_word("учи́ться", "нсв 4c".parse().unwrap()).unwrap();
+    /// assert_eq!(verb.stem().as_str(), "учи");
+    /// assert!(verb.info().reflexive);
+    ///
+    /// let result = Verb::from_word("учи́тель", "нсв 4c".parse().unwrap());
+    /// assert_eq!(result, Err(NewVerbError::NotInfinitive));
+    /// ```
+    pub fn from_word(word: &str, mut info: VerbInfo) -> Result<Self, NewVerbError> {
+        use Utf8Letter::*;
+
+        let mut stem: WordBuf = word.parse().or(Err(NewVerbError::InvalidStem))?;
+
+        // Trim the reflexive postfix: -ся or -сь. Reflexive verbs are always intransitive.
+        if let [.., С, Я | Ь] = stem.as_letters() {
+            stem.set_stem_len(stem.as_letters().len() - 2);
+            info.reflexive = true;
+            info.transitivity = Transitivity::Intransitive;
+        }
+
+        // Trim the infinitive ending: -ть, -ти or -чь
+        let stem_len = match stem.as_letters() {
+            [.., Т, Ь | И] | [.., Ч, Ь] => stem.as_letters().len() - 2,
+            _ => return Err(NewVerbError::NotInfinitive),
+        };
+        let stressed_ending = stem.stress_at > stem_len;
+        stem.set_stem_len(stem_len);
+
+        // If the stress was on the ending (нести́), keep it on the last stem vowel instead.
+        // The stem's stress is only used when the stem receives the stress (нёс, несла́).
+        if stressed_ending {
+            let last_vowel = stem.as_letters().iter().rposition(|x| x.is_vowel());
+            stem.stress_at = last_vowel.map_or(0, |x| x + 1);
+        }
+
+        Ok(Self { stem, info })
+    }
+    /// Returns the verb's infinitive stem.
+    #[must_use]
+    pub const fn stem(&self) -> Word<'_> {
+        self.stem.borrow()
+    }
+    /// Returns the verb's grammatical info.
+    #[must_use]
+    pub const fn info(&self) -> VerbInfo {
+        self.info
+    }
+}
@@ -0,0 +1,432 @@
+use crate::{
+    adjective::{Adjective, AdjectiveFlags, AdjectiveInfo, AdjectiveKind},
+    categories::{ConjInfo, Gender, Number, Person, Tense},
+    conjugation::{Conjugation, ConjugationClass},
+    declension::{AdjectiveDeclension, AdjectiveStemType, Declension, DeclensionFlags},
+    stress::AdjectiveStress,
+    util::InflectionBuf,
+    verb::{Aspect, ParticipleKind, Transitivity, Verb, VerbInfo},
+    word::{Utf8Letter, Word, WordBuf},
+};
+
+impl Verb {
+    /// Forms the verb's participle of the specified kind. Participles decline like adjectives.
+    ///
+    /// Returns `None` if the verb doesn't have such a participle: perfective verbs don't have
+    /// present participles, and intransitive verbs don't have passive participles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zaliznyak::{
+    ///     categories::{Animacy, Case, DeclInfo, Gender, Number},
+    ///     verb::{ParticipleKind, Verb},
+    /// };
+    ///
+    /// let verb = Verb::from_word("чита́ть", "нсв 1a".parse().unwrap()).unwrap();
+    /// let participle = verb.participle(ParticipleKind::PresentActive).unwrap();
+    ///
+    /// let info = DeclInfo {
+    ///     case: Case::Genitive,
+    ///     number: Number::Singular,
+    ///     gender: Gender::Feminine,
+    ///     animacy: Animacy::Animate,
+    /// };
+    /// assert_eq!(participle.inflect(info).as_str(), "читающей");
+    ///
+    /// let verb = Verb::from_word("спа́ть", "нсв-нп 5b/c".parse().unwrap()).unwrap();
+    /// assert_eq!(verb.participle(ParticipleKind::PastPassive), None);
+    /// ```
+    pub fn participle(&self, kind: ParticipleKind) -> Option<Adjective> {
+        self.info.participle(self.stem.borrow(), kind)
+    }
+}
+
+impl VerbInfo {
+    /// Forms the participle of a verb with the specified stem. See [`Verb::participle`].
+    pub fn participle(&self, stem: Word, kind: ParticipleKind) -> Option<Adjective> {
+        use ParticipleKind::*;
+
+        // Only imperfective verbs have present participles,
+        // and only transitive verbs have passive participles.
+        if self.aspect == Aspect::Perfective && matches!(kind, PresentActive | PresentPassive)
+            || self.transitivity == Transitivity::Intransitive
+                && matches!(kind, PresentPassive | PastPassive)
+        {
+            return None;
+        }
+
+        let conj = self.conjugation?;
+
+        let mut word = WordBuf::with_stem(stem, 6);
+        let mut buf = InflectionBuf::new(&mut word);
+
+        let decl = match kind {
+            PresentActive => conj.form_present_active_participle(&mut buf),
+            PastActive => conj.form_past_active_participle(&mut buf),
+            PresentPassive => conj.form_present_passive_participle(&mut buf)?,
+            PastPassive => conj.form_past_passive_participle(&mut buf)?,
+        };
+
+        // The whole formed word is the participle's stem
+        buf.stem_len = buf.len;
+        buf.finish(&mut word);
+
+        // Active participles don't have short forms, and none of the participles have comparatives
+        let flags = match kind {
+            PresentActive | PastActive => AdjectiveFlags::BOXED_CROSS,
+            PresentPassive | PastPassive => AdjectiveFlags::empty(),
+        };
+        let info = AdjectiveInfo {
+            declension: Some(Declension::Adjective(decl)),
+            flags: flags.union(AdjectiveFlags::NO_COMPARATIVE_FORM),
+            kind: AdjectiveKind::Regular,
+        };
+        Some(if self.reflexive {
+            Adjective::from_stem_reflexive(word, info)
+        } else {
+            Adjective::from_stem(word, info)
+        })
+    }
+}
+
+const fn present(number: Number, person: Person) -> ConjInfo {
+    ConjInfo { tense: Tense::Present, number, person, gender: Gender::Masculine }
+}
+const fn decl(
+    stem_type: AdjectiveStemType,
+    stress: AdjectiveStress,
+    flags: DeclensionFlags,
+) -> AdjectiveDeclension {
+    AdjectiveDeclension { stem_type, stress, flags }
+}
+
+impl Conjugation {
+    fn form_present_active_participle(self, buf: &mut InflectionBuf) -> AdjectiveDeclension {
+        // Formed from the 3rd person plural form: читают - читающий, несут - несущий
+        self.inflect(present(Number::Plural, Person::Third), buf);
+
+        if let Some(last) = buf.as_mut_slice().last_mut() {
+            *last = Utf8Letter::Щ;
+        }
+        self.stress_like_first_singular(buf);
+
+        decl(AdjectiveStemType::Type4, AdjectiveStress::A, DeclensionFlags::empty())
+    }
+
+    fn form_past_active_participle(self, buf: &mut InflectionBuf) -> AdjectiveDeclension {
+        self.form_past_active_stem(buf);
+        buf.append_to_ending("ш");
+
+        decl(AdjectiveStemType::Type4, AdjectiveStress::A, DeclensionFlags::empty())
+    }
+
+    fn form_present_passive_participle(
+        self,
+        buf: &mut InflectionBuf,
+    ) -> Option<AdjectiveDeclension> {
+        use ConjugationClass::*;
+
+        match self.class {
+            // давать - даваемый, узнавать - узнаваемый
+            Class13 => buf.append_to_stem("ем"),
+            // Formed from the 1st person plural form: читаем - читаемый, любим - любимый
+            Class1 | Class2 | Class4 | Class5 | Class7 => {
+                self.inflect(present(Number::Plural, Person::First), buf);
+                self.stress_like_first_singular(buf);
+
+                // After consonants, 'ём' becomes 'ом' (ведём - ведомый, несём - несомый)
+                let (stem, ending) = buf.stem_and_ending_mut();
+                if stem.last().is_some_and(|x| x.is_consonant())
+                    && let Some(yo @ Utf8Letter::Ё) = ending.first_mut()
+                {
+                    *yo = Utf8Letter::О;
+                }
+            },
+            // Present passive participles of other classes are not used (*пишемый, *берёмый)
+            _ => return None,
+        };
+
+        Some(decl(AdjectiveStemType::Type1, AdjectiveStress::A, DeclensionFlags::empty()))
+    }
+
+    fn form_past_passive_participle(self, buf: &mut InflectionBuf) -> Option<AdjectiveDeclension> {
+        use {ConjugationClass::*, Utf8Letter::*};
+
+        let stress_at = buf.stress_at;
+        let tyj = decl(AdjectiveStemType::Type1, AdjectiveStress::A, DeclensionFlags::empty());
+
+        Some(match self.class {
+            // просить - прошенный, любить - любленный, решить - решённый, обидеть - обиженный
+            Class4 | Class5 if let [.., И | Е] = buf.stem() => {
+                buf.shrink_stem_by(1);
+                Self::alternate_consonant(buf);
+                self.append_enn_suffix(stress_at, buf)
+            },
+            // прочитать - прочитанный, нарисовать - нарисованный, написать - написанный,
+            // держать (5c) - держанный
+            Class1 | Class2 | Class4 | Class5 | Class6 => {
+                Self::shift_stress_back(buf);
+                buf.append_to_stem("нн");
+                decl(AdjectiveStemType::Type1, AdjectiveStress::A, DeclensionFlags::CIRCLED_TWO)
+            },
+            // нести - несённый, вести (-д-) - ведённый, печь (-к-) - печённый
+            Class7 | Class8 => {
+                self.form_present_stem(present(Number::Singular, Person::Second), buf);
+                self.append_enn_suffix(stress_at, buf)
+            },
+            // толкнуть - толкнутый, колоть - колотый, начать - начатый
+            Class3 | Class10 | Class14 => {
+                Self::shift_stress_back(buf);
+                buf.append_to_stem("т");
+                tyj
+            },
+            // тереть - тёртый
+            Class9 => {
+                self.form_past_stem(buf);
+                self.place_stress(stress_at, buf);
+                if self.flags.has_alternating_yo() {
+                    self.apply_ye_yo_alternation(buf);
+                }
+                buf.append_to_stem("т");
+                tyj
+            },
+            // бить - битый, мыть - мытый, одеть - одетый, прожить - прожитый
+            Class11 | Class12 | Class15 | Class16 => {
+                buf.append_to_stem("т");
+                tyj
+            },
+            // Past passive participles of class 13 verbs are not used (*даванный)
+            Class13 => return None,
+        })
+    }
+
+    /// Forms the stem shared by past active participles and gerunds with -ши, from the masculine
+    /// past form: читал - читав(ший), нёс - нёс(ший), тёр - тёр(ший), вёл - вед(ший).
+    pub(super) fn form_past_active_stem(self, buf: &mut InflectionBuf) {
+        use Utf8Letter::*;
+
+        let info = ConjInfo { tense: Tense::Past, ..present(Number::Singular, Person::Third) };
+        self.inflect(info, buf);
+
+        match (self.class, self.stem_consonant) {
+            // вести (-д-) - ведший, мести (-т-) - метший
+            (ConjugationClass::Class7, Some(consonant @ (Д | Т))) => {
+                let word = buf.as_mut_slice();
+                if let Some(last) = word.last_mut() {
+                    *last = consonant;
+                }
+                if let Some(yo) = word.iter_mut().find(|x| **x == Ё) {
+                    *yo = Е;
+                }
+            },
+            // читать - читал - читавший
+            _ if let Some(last @ Л) = buf.as_mut_slice().last_mut() => *last = В,
+            _ => {},
+        };
+    }
+
+    fn append_enn_suffix(self, stress_at: usize, buf: &mut InflectionBuf) -> AdjectiveDeclension {
+        // If the 2nd person singular form is stressed on the ending (решишь, несёшь),
+        // then the suffix is stressed too (решённый, несённый). Otherwise, the stem is stressed.
+        if self.stress.present.is_stem_stressed(Number::Singular, Person::Second) {
+            self.place_stress(stress_at, buf);
+            buf.append_to_stem("енн");
+            decl(AdjectiveStemType::Type1, AdjectiveStress::A, DeclensionFlags::CIRCLED_TWO)
+        } else {
+            buf.append_to_stem("ённ");
+            buf.stress_at = buf.stem_len - 2;
+
+            let flags = DeclensionFlags::CIRCLED_TWO.union(DeclensionFlags::ALTERNATING_YO);
+            decl(AdjectiveStemType::Type1, AdjectiveStress::A_B, flags)
+        }
+    }
+
+    fn stress_like_first_singular(self, buf: &mut InflectionBuf) {
+        // Second conjugation participles are stressed like the 1st person singular form,
+        // and not like the form they're formed from: лю́бят - любя́щий, лю́бим - люби́мый.
+        if self.class.is_second_conjugation()
+            && !self.stress.present.is_stem_stressed(Number::Singular, Person::First)
+            && let Some(pos) = buf.as_slice().iter().rposition(|x| x.is_vowel())
+        {
+            buf.stress_at = pos + 1;
+        }
+    }
+
+    fn shift_stress_back(buf: &mut InflectionBuf) {
+        // The stress moves away from the stem's last vowel, if it's stressed and there's
+        // a vowel before it: прочита́ть - прочи́танный, толкну́ть - то́лкнутый, нача́ть - на́чатый.
+        let stem = buf.stem();
+
+        if let Some(last) = stem.iter().rposition(|x| x.is_vowel())
+            && buf.stress_at == last + 1
+            && let Some(prev) = stem[..last].iter().rposition(|x| x.is_vowel())
+        {
+            buf.stress_at = prev + 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Animacy, Case, DeclInfo},
+        word::Accent,
+    };
+
+    fn part(word: &str, info: &str, kind: ParticipleKind) -> Option<String> {
+        let verb = Verb::from_word(word, info.parse().unwrap()).unwrap();
+        let adj = verb.participle(kind)?;
+
+        let (case, number, animacy) = (Case::Nominative, Number::Singular, Animacy::Inanimate);
+        let full = adj.inflect(DeclInfo { case, number, gender: Gender::Masculine, animacy });
+        Some(full.display().accent(Accent::explicit(Accent::ACUTE)).to_string())
+    }
+    fn short(word: &str, info: &str) -> String {
+        let verb = Verb::from_word(word, info.parse().unwrap()).unwrap();
+        let adj = verb.participle(ParticipleKind::PastPassive).unwrap();
+
+        let (case, animacy) = (Case::Nominative, Animacy::Inanimate);
+        [
+            (Number::Singular, Gender::Masculine),
+            (Number::Singular, Gender::Feminine),
+            (Number::Singular, Gender::Neuter),
+            (Number::Plural, Gender::Masculine),
+        ]
+        .map(|(number, gender)| {
+            let word = adj.inflect_short(DeclInfo { case, number, gender, animacy }, false);
+            word.unwrap().as_str().to_owned()
+        })
+        .join(", ")
+    }
+
+    #[test]
+    fn present_active() {
+        let assert_part = |word, info, expected: &str| {
+            assert_eq!(part(word, info, ParticipleKind::PresentActive).as_deref(), Some(expected));
+        };
+
+        assert_part("чита́ть", "нсв 1a", "чита́ющий");
+        assert_part("рисова́ть", "нсв 2a", "рису́ющий");
+        assert_part("писа́ть", "нсв 6c", "пи́шущий");
+        assert_part("нести́", "нсв 7b/b, ё", "несу́щий");
+        assert_part("пла́кать", "нсв-нп 6a", "пла́чущий");
+        assert_part("люби́ть", "нсв 4c", "любя́щий");
+        assert_part("ви́деть", "нсв 5a", "ви́дящий");
+        assert_part("держа́ть", "нсв 5c", "держа́щий");
+
+        // Perfective verbs don't have present participles
+        assert_eq!(part("прочита́ть", "св 1a", ParticipleKind::PresentActive), None);
+    }
+
+    #[test]
+    fn past_active() {
+        let assert_part = |word, info, expected: &str| {
+            assert_eq!(part(word, info, ParticipleKind::PastActive).as_deref(), Some(expected));
+        };
+
+        assert_part("чита́ть", "нсв 1a", "чита́вший");
+        assert_part("прочита́ть", "св 1a", "прочита́вший");
+        assert_part("бра́ть", "нсв 6°b/c", "бра́вший");
+        assert_part("нести́", "нсв 7b/b, ё", "нё́сший");
+        assert_part("вести́", "нсв 7b/b(-д-), ё", "ве́дший");
+        assert_part("печь", "нсв 8b/b(-к-), ё", "пё́кший");
+        assert_part("тере́ть", "нсв 9b, ё", "тё́рший");
+    }
+
+    #[test]
+    fn present_passive() {
+        let assert_part = |word, info, expected: Option<&str>| {
+            assert_eq!(part(word, info, ParticipleKind::PresentPassive).as_deref(), expected);
+        };
+
+        assert_part("чита́ть", "нсв 1a", Some("чита́емый"));
+        assert_part("рисова́ть", "нсв 2a", Some("рису́емый"));
+        assert_part("люби́ть", "нсв 4c", Some("люби́мый"));
+        assert_part("ви́деть", "нсв 5a", Some("ви́димый"));
+        assert_part("вести́", "нсв 7b/b(-д-), ё", Some("ведо́мый"));
+        assert_part("дава́ть", "нсв 13b", Some("дава́емый"));
+
+        assert_part("писа́ть", "нсв 6c", None);
+        assert_part("спа́ть", "нсв-нп 5b/c", None);
+        assert_part("прочита́ть", "св 1a", None);
+    }
+
+    #[test]
+    fn past_passive() {
+        let assert_part = |word, info, expected: &str| {
+            assert_eq!(part(word, info, ParticipleKind::PastPassive).as_deref(), Some(expected));
+        };
+
+        assert_part("прочита́ть", "св 1a", "прочи́танный");
+        assert_part("сде́лать", "св 1a", "сде́ланный");
+        assert_part("нарисова́ть", "св 2a", "нарисо́ванный");
+        assert_part("написа́ть", "св 6c", "напи́санный");
+        assert_part("собра́ть", "св 6°b/c", "со́бранный");
+        assert_part("услы́шать", "св 5a", "услы́шанный");
+        assert_part("толкну́ть", "св 3b", "то́лкнутый");
+        assert_part("ста́вить", "нсв 4a", "ста́вленный");
+        assert_part("купи́ть", "св 4c", "ку́пленный");
+        assert_part("спроси́ть", "св 4c", "спро́шенный");
+        assert_part("реши́ть", "св 4b", "решё́нный");
+        assert_part("оби́деть", "св 5a", "оби́женный");
+        assert_part("принести́", "св 7b/b, ё", "принесё́нный");
+        assert_part("привести́", "св 7b/b(-д-), ё", "приведё́нный");
+        assert_part("испе́чь", "св 8b/b(-к-), ё", "испечё́нный");
+        assert_part("стере́ть", "св 9b, ё", "стё́ртый");
+        assert_part("расколо́ть", "св 10c", "раско́лотый");
+        assert_part("уби́ть", "св 11b", "уби́тый");
+        assert_part("вы́мыть", "св 12a", "вы́мытый");
+        assert_part("нача́ть", "св 14b/c(-н-)", "на́чатый");
+        assert_part("оде́ть", "св 15a", "оде́тый");
+
+        // Intransitive verbs don't have passive participles
+        assert_eq!(part("спа́ть", "нсв-нп 5b/c", ParticipleKind::PastPassive), None);
+
+        // Short forms
+        assert_eq!(short("прочита́ть", "св 1a"), "прочитан, прочитана, прочитано, прочитаны");
+        assert_eq!(short("реши́ть", "св 4b"), "решён, решена, решено, решены");
+        assert_eq!(short("уби́ть", "св 11b"), "убит, убита, убито, убиты");
+    }
+
+    #[test]
+    fn reflexive() {
+        let verb = Verb::from_word("учи́ться", "нсв 4c".parse().unwrap()).unwrap();
+        let adj = verb.participle(ParticipleKind::PresentActive).unwrap();
+
+        let animacy = Animacy::Inanimate;
+        let forms = [
+            (Case::Nominative, Number::Singular, Gender::Masculine),
+            (Case::Genitive, Number::Singular, Gender::Masculine),
+            (Case::Nominative, Number::Singular, Gender::Feminine),
+            (Case::Nominative, Number::Plural, Gender::Masculine),
+        ]
+        .map(|(case, number, gender)| adj.inflect(DeclInfo { case, number, gender, animacy }));
+        assert_eq!(forms.map(|x| x.as_str().to_owned()), [
+            "учащийся",
+            "учащегося",
+            "учащаяся",
+            "учащиеся",
+        ]);
+        assert_eq!(
+            adj.inflect_short(
+                DeclInfo {
+                    case: Case::Nominative,
+                    number: Number::Singular,
+                    gender: Gender::Masculine,
+                    animacy
+                },
+                true
+            ),
+            None
+        );
+
+        // Reflexive verbs are intransitive, and don't have passive participles
+        assert_eq!(verb.participle(ParticipleKind::PresentPassive), None);
+        assert_eq!(
+            part("учи́ться", "нсв 4c", ParticipleKind::PastActive).as_deref(),
+            Some("учи́вшийся")
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::word::{ParseWordError, WordBuf};
+
+/// A casing mask of a word, indicating which of its letters are uppercase.
+///
+/// Since [`WordBuf`] can only contain lowercase letters, capitalized words have to be lowercased
+/// before parsing. The casing mask of the original string can be captured, and then applied to
+/// the inflected form: **Москва** --- **Москвой**, **РОМАШКА** --- **РОМАШКОЙ**. If the inflected
+/// form is longer than the original word, the extra letters are uppercase only if the original
+/// word ended in two or more uppercase letters.
+///
+/// The mask is positional: only alphabetic characters are counted as letters, so stress marks,
+/// hyphens and quotes don't affect it, but letters inserted or removed by inflection in the
+/// middle of the word do.
+///
+/// # Examples
+///
+/// ```
+/// use zaliznyak::{
+///     categories::{CaseEx, Number},
+///     noun::Noun,
+///     word::{Casing, WordBuf},
+/// };
+///
+/// let (word, casing) = Casing::strip("Москва́");
+/// assert_eq!(word, "москва́");
+///
+/// let noun = Noun::from_word(&word, "ж 1d".parse().unwrap()).unwrap();
+/// let inflected = noun.inflect(CaseEx::Instrumental, Number::Singular);
+/// assert_eq!(casing.apply(inflected.as_str()), "Москвой");
+///
+/// let (word, casing) = WordBuf::parse_cased("«РОМА́ШКА»").unwrap();
+/// assert_eq!(word.as_str(), "ромашка");
+/// assert_eq!(casing.apply("ромашкой"), "РОМАШКОЙ");
+/// ```
+#[derive(Debug, Copy, Eq, Hash)]
+#[derive_const(Default, Clone, PartialEq)]
+pub struct Casing {
+    mask: u64,
+    len: usize,
+}
+
+impl Casing {
+    /// Captures the casing mask of a string.
+    #[must_use]
+    pub fn of(s: &str) -> Self {
+        let mut casing = Self::default();
+
+        for ch in s.chars().filter(|x| x.is_alphabetic()) {
+            if ch.is_uppercase() && casing.len < u64::BITS as usize {
+                casing.mask |= 1 << casing.len;
+            }
+            casing.len += 1;
+        }
+        casing
+    }
+    /// Lowercases a string, returning the lowercase string and the original string's casing
+    /// mask. Punctuation surrounding the word, such as quotes or brackets, is removed: the
+    /// caller is responsible for wrapping the inflected form in it again, if necessary.
+    #[must_use]
+    pub fn strip(s: &str) -> (String, Self) {
+        let s = s.trim_matches(is_punctuation);
+        (s.to_lowercase(), Self::of(s))
+    }
+
+    /// Returns `true` if all of the letters are lowercase.
+    #[must_use]
+    pub const fn is_lowercase(self) -> bool {
+        self.mask == 0
+    }
+    /// Returns `true` if only the first letter is uppercase.
+    #[must_use]
+    pub const fn is_title_case(self) -> bool {
+        self.mask == 1
+    }
+    /// Returns `true` if all of the letters are uppercase, and there's at least one.
+    #[must_use]
+    pub const fn is_uppercase(self) -> bool {
+        let full = if self.len >= u64::BITS as usize { u64::MAX } else { (1 << self.len) - 1 };
+        self.len > 0 && self.mask == full
+    }
+
+    /// Returns `true` if the letter at the specified index is uppercase.
+    const fn is_upper_at(self, index: usize) -> bool {
+        if index >= self.len {
+            // Only extend the casing of all-caps words: Москва --- Москвой, МОСКВА --- МОСКВОЙ
+            return self.len >= 2
+                && self.is_upper_at(self.len - 1)
+                && self.is_upper_at(self.len - 2);
+        }
+        let index = if index < u64::BITS as usize { index } else { u64::BITS as usize - 1 };
+        self.mask & (1 << index) != 0
+    }
+
+    /// Applies the casing mask to a lowercase string.
+    #[must_use]
+    pub fn apply(self, s: &str) -> String {
+        if self.is_lowercase() {
+            return s.to_owned();
+        }
+        let mut result = String::with_capacity(s.len());
+        let mut index = 0;
+
+        for ch in s.chars() {
+            if !ch.is_alphabetic() {
+                result.push(ch);
+                continue;
+            }
+            if self.is_upper_at(index) {
+                result.extend(ch.to_uppercase());
+            } else {
+                result.push(ch);
+            }
+            index += 1;
+        }
+        result
+    }
+}
+
+/// Returns `true` if the character can't be a part of a word. Stress marks (including the ASCII
+/// apostrophe), letters and digits are considered parts of a word.
+fn is_punctuation(ch: char) -> bool {
+    !ch.is_alphanumeric() && !matches!(ch, '\u{301}' | '\u{300}' | '\'')
+}
+
+impl WordBuf {
+    /// Parses a word, that may contain uppercase letters, returning the lowercase word and the
+    /// original string's casing mask. Punctuation surrounding the word is removed, see
+    /// [`Casing::strip`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseWordError`] if the lowercased string couldn't be parsed as a word.
+    pub fn parse_cased(s: &str) -> Result<(Self, Casing), ParseWordError> {
+        let (s, casing) = Casing::strip(s);
+        Ok((s.parse()?, casing))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{CaseEx, Number},
+        dictionary::{Entry, Lexicon},
+    };
+
+    #[test]
+    fn casing() {
+        let apply = |original: &str, s: &str| Casing::of(original).apply(s);
+
+        assert_eq!(apply("москва", "москвой"), "москвой");
+        assert_eq!(apply("Москва", "москвой"), "Москвой");
+        assert_eq!(apply("МОСКВА", "москвой"), "МОСКВОЙ");
+        assert_eq!(apply("«РОМАШКА»", "ромашкой"), "РОМАШКОЙ");
+        assert_eq!(apply("МакДональдс", "макдональдсом"), "МакДональдсом");
+        assert_eq!(apply("Я", "меня"), "Меня");
+        assert_eq!(apply("", "меня"), "меня");
+
+        assert!(Casing::of("Москва́").is_title_case());
+        assert!(Casing::of("МОСКВА").is_uppercase());
+        assert!(!Casing::of("МОСКВа").is_uppercase());
+        assert!(Casing::of("москва").is_lowercase());
+        assert!(!Casing::of("").is_uppercase());
+
+        let long = "А".repeat(70);
+        assert_eq!(Casing::of(&long).apply(&"а".repeat(72)), "А".repeat(72));
+
+        assert_eq!(WordBuf::parse_cased("ГО́РОД").map(|x| x.1), Ok(Casing::of("ГОРОД")));
+        assert_eq!(WordBuf::parse_cased("City"), Err(ParseWordError::NonCyrillic));
+        assert_eq!(WordBuf::parse_cased("(Ру́сь)").map(|x| x.0.to_string()), Ok("русь".into()));
+        assert_eq!(WordBuf::parse_cased("«РОМАШКА-2»"), Err(ParseWordError::NonCyrillic));
+        assert_eq!(Casing::strip("«Го́род»,"), ("го́род".to_owned(), Casing::of("Город")));
+    }
+
+    #[test]
+    fn inflect_cased() {
+        let (lexicon, _) = Lexicon::parse("рома́шка ж 3*a");
+
+        let name = "ООО «РОМАШКА»";
+        let (word, casing) = Casing::strip(name.rsplit(' ').next().unwrap());
+        let Some(Entry::Noun(noun)) = lexicon.get(&word).next() else { panic!() };
+        let inflected = noun.inflect(CaseEx::Instrumental, Number::Singular);
+        assert_eq!(casing.apply(inflected.as_str()), "РОМАШКОЙ");
+    }
+}
@@ -215,6 +215,8 @@ impl fmt::Debug for WordBuf {
 }
 
 impl fmt::Display for Display<'_> {
+    // The nested write!/format! calls don't have width/precision set, so they don't recurse again
+    #[allow(clippy::recursive_format_impl)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // If there are alignment requirements, format to a buffer/String, and then pad/align/trunc
         if f.width().is_some() || f.precision().is_some() {
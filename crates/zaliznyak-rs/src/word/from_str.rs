@@ -40,6 +40,24 @@ pub(super) fn find_implicit_insert_stress_pos(word: &[Utf8Letter]) -> Option<usi
     result
 }
 
+/// Places the stress on the first vowel of a word, unless it contains ё. Used for words, whose
+/// stress isn't known, where its position in the stem doesn't affect the letters of the forms.
+pub(crate) fn with_default_stress(word: &str) -> String {
+    if word.contains('ё') {
+        return word.to_owned();
+    }
+    let mut s = String::with_capacity(word.len() + 2);
+    let mut stressed = false;
+    for ch in word.chars() {
+        s.push(ch);
+        if !stressed && "аеёиоуыэюя".contains(ch) {
+            s.push('\u{301}');
+            stressed = true;
+        }
+    }
+    s
+}
+
 // TODO: constify WordBuf::from_str?
 impl std::str::FromStr for WordBuf {
     type Err = ParseWordError;
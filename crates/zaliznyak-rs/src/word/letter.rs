@@ -42,6 +42,7 @@ const fn d(ch: char) -> u16 {
 const fn quick_encode_utf8(codepoint: u16) -> [u8; 2] {
     [(codepoint >> 6) as u8 | 0xC0, (codepoint & 0x3F) as u8 | 0x80]
 }
+#[allow(dead_code)]
 const fn quick_decode_utf8(utf8: [u8; 2]) -> u16 {
     (((utf8[0] & 0x1F) as u16) << 6) | ((utf8[1] & 0x3F) as u16)
 }
@@ -79,11 +79,24 @@
 //!
 //! assert_eq!("слов-о".parse::<WordBuf>(), Err(ParseWordError::NoStress));
 //! ```
+//!
+//! Words containing uppercase letters can be parsed with [`WordBuf::parse_cased`], which also
+//! returns the word's [`Casing`], that can then be applied to its inflected forms.
+//!
+//! ```
+//! use zaliznyak::word::WordBuf;
+//!
+//! let (buf, casing) = WordBuf::parse_cased("Москва́").unwrap();
+//! assert_eq!(buf.as_str(), "москва");
+//! assert_eq!(casing.apply("москве"), "Москве");
+//! ```
 
+mod casing;
 mod display;
 mod from_str;
 mod letter;
 
+pub use casing::*;
 pub use display::*;
 pub use from_str::*;
 pub use letter::*;
@@ -303,6 +316,10 @@ impl<'a> Word<'a> {
         self.ending_letters().as_str()
     }
 
+    pub(crate) const fn stress_at(&self) -> usize {
+        self.stress_at
+    }
+
     /// Creates an owned [`WordBuf`] from this word slice.
     #[must_use]
     pub fn to_owned(&self) -> WordBuf {